@@ -85,6 +85,7 @@ pub async fn main() -> miette::Result<()> {
     let (app, mut openapi) = OpenApiRouter::new()
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(Duration::from_secs(30)))
+        .routes(routes!(hartex_backend_routes::errors::get_error_report))
         .routes(routes!(
             hartex_backend_routes::uptime::get_uptime,
             hartex_backend_routes::uptime::patch_uptime
@@ -0,0 +1,118 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Error Models V2
+//!
+//! Models for the error report API specification V2 of the backend.
+
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// A persisted error report, looked up by its error code.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Deserialize, Serialize, ToSchema)]
+pub struct ErrorReportResponse {
+    code: String,
+    kind: String,
+    report: String,
+    command: Option<String>,
+    guild_id: Option<String>,
+    user_id: Option<String>,
+    options: Option<String>,
+    timestamp: u128,
+}
+
+impl ErrorReportResponse {
+    /// Constructs a response to an error report query.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        code: impl Into<String>,
+        kind: impl Into<String>,
+        report: impl Into<String>,
+        command: Option<String>,
+        guild_id: Option<String>,
+        user_id: Option<String>,
+        options: Option<String>,
+        timestamp: u128,
+    ) -> Self {
+        Self {
+            code: code.into(),
+            kind: kind.into(),
+            report: report.into(),
+            command,
+            guild_id,
+            user_id,
+            options,
+            timestamp,
+        }
+    }
+
+    /// The error code of the error report.
+    #[must_use]
+    pub fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    /// The kind of the error, either `error` or `panic`.
+    #[must_use]
+    pub fn kind(&self) -> &str {
+        self.kind.as_str()
+    }
+
+    /// The error report or panic message.
+    #[must_use]
+    pub fn report(&self) -> &str {
+        self.report.as_str()
+    }
+
+    /// The command or component the error occurred in, if any.
+    #[must_use]
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+
+    /// The guild the error occurred in, if any.
+    #[must_use]
+    pub fn guild_id(&self) -> Option<&str> {
+        self.guild_id.as_deref()
+    }
+
+    /// The user that triggered the error, if any.
+    #[must_use]
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_id.as_deref()
+    }
+
+    /// The serialized interaction data, including the options the command was invoked with.
+    #[must_use]
+    pub fn options(&self) -> Option<&str> {
+        self.options.as_deref()
+    }
+
+    /// The timestamp the error occurred at.
+    #[must_use]
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+}
@@ -34,6 +34,7 @@ use either::Either;
 use serde::Deserialize;
 use serde::Serialize;
 
+pub mod errors;
 pub mod uptime;
 
 /// An API response object.
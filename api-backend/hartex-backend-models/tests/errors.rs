@@ -0,0 +1,70 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_backend_models::errors::ErrorReportResponse;
+use serde_json::json;
+
+fn report() -> ErrorReportResponse {
+    ErrorReportResponse::new(
+        "3f2a",
+        "error",
+        "failed to query guild",
+        Some(String::from("info guild")),
+        Some(String::from("1234")),
+        Some(String::from("5678")),
+        None,
+        1_700_000_000,
+    )
+}
+
+#[test]
+fn error_report_response_serializes_every_field() {
+    assert_eq!(
+        serde_json::to_value(report()).unwrap(),
+        json!({
+            "code": "3f2a",
+            "kind": "error",
+            "report": "failed to query guild",
+            "command": "info guild",
+            "guild_id": "1234",
+            "user_id": "5678",
+            "options": null,
+            "timestamp": 1_700_000_000,
+        })
+    );
+}
+
+#[test]
+fn error_report_response_round_trips() {
+    let report =
+        serde_json::from_str::<ErrorReportResponse>(&serde_json::to_string(&report()).unwrap())
+            .unwrap();
+
+    assert_eq!(report.code(), "3f2a");
+    assert_eq!(report.kind(), "error");
+    assert_eq!(report.report(), "failed to query guild");
+    assert_eq!(report.command(), Some("info guild"));
+    assert_eq!(report.guild_id(), Some("1234"));
+    assert_eq!(report.user_id(), Some("5678"));
+    assert_eq!(report.options(), None);
+    assert_eq!(report.timestamp(), 1_700_000_000);
+}
@@ -0,0 +1,82 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Error Routes
+//!
+//! Routes interacting with the error report API.
+
+use axum::Json;
+use axum::extract::Path;
+use axum::http::StatusCode;
+use hartex_backend_models::Response;
+use hartex_backend_models::errors::ErrorReportResponse;
+use hartex_database_queries::queries::discord_frontend::error_report_select_by_hash::ErrorReportSelectByHash;
+use hartex_log::log;
+
+/// Get error report
+#[allow(clippy::cast_sign_loss)]
+#[allow(clippy::missing_panics_doc)] // this function cannot panic
+#[allow(clippy::module_name_repetitions)]
+#[utoipa::path(
+    get,
+    path = "/api/v1/errors/{code}",
+    params(
+        ("code" = String, Path, description = "The error code quoted to the user")
+    ),
+    responses(
+        (status = 200, description = "Error report retrieved successfully", body = ErrorReportResponse),
+        (status = 404, description = "Specified error code not found in error database"),
+        (status = 500, description = "Generic internal server error")
+    )
+)]
+pub async fn get_error_report(
+    Path(code): Path<String>,
+) -> (StatusCode, Json<Response<ErrorReportResponse, String>>) {
+    log::trace!("querying error report");
+    let result = ErrorReportSelectByHash::bind(code).executor().await;
+
+    if result.is_err() {
+        return Response::internal_server_error();
+    }
+
+    let result = result.unwrap().many().await;
+
+    if result.is_err() {
+        return Response::internal_server_error();
+    }
+
+    let vec = result.unwrap();
+    let Some(report) = vec.first() else {
+        return Response::not_found(String::from("error report"));
+    };
+
+    Response::ok(ErrorReportResponse::new(
+        report.hash(),
+        report.kind(),
+        report.report(),
+        report.command().map(String::from),
+        report.guild_id().map(String::from),
+        report.user_id().map(String::from),
+        report.options().map(String::from),
+        report.timestamp().timestamp() as u128,
+    ))
+}
@@ -0,0 +1,37 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+CREATE TABLE IF NOT EXISTS "Nightly"."CommandPermissions" (
+    "guild_id" TEXT NOT NULL,
+    "command" TEXT NOT NULL,
+    "replace_required_permissions" BOOLEAN NOT NULL,
+    PRIMARY KEY("guild_id", "command")
+);
+
+CREATE TABLE IF NOT EXISTS "Nightly"."CommandPermissionRules" (
+    "guild_id" TEXT NOT NULL,
+    "command" TEXT NOT NULL,
+    "kind" TEXT NOT NULL,
+    "target_id" TEXT NOT NULL,
+    "allow" BOOLEAN NOT NULL,
+    PRIMARY KEY("guild_id", "command", "kind", "target_id")
+);
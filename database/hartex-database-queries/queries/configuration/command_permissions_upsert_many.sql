@@ -0,0 +1,30 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+-- permissions:TEXT
+INSERT INTO "Configuration"."Nightly"."CommandPermissions" ("guild_id", "command", "replace_required_permissions")
+SELECT
+    "guild_id", "command", "replace_required_permissions"
+FROM
+    JSONB_TO_RECORDSET(CAST(:permissions AS JSONB)) AS "Permissions" ("guild_id" TEXT, "command" TEXT, "replace_required_permissions" BOOLEAN)
+ON CONFLICT ("guild_id", "command") DO UPDATE
+    SET
+        "replace_required_permissions" = "excluded"."replace_required_permissions";
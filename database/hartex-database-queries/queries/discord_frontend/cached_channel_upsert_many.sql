@@ -0,0 +1,40 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+-- channels:TEXT
+INSERT INTO "DiscordFrontend"."Nightly"."CachedChannels" ("guild_id", "id", "kind", "name", "nsfw", "parent_id", "permission_overwrites", "position", "rate_limit_per_user", "thread_metadata", "topic")
+SELECT
+    "guild_id", "id", "kind", "name", "nsfw", "parent_id", "permission_overwrites", "position", "rate_limit_per_user", "thread_metadata", "topic"
+FROM
+    JSONB_TO_RECORDSET(CAST(:channels AS JSONB)) AS "Channels" ("guild_id" TEXT, "id" TEXT, "kind" SMALLINT, "name" TEXT, "nsfw" BOOLEAN, "parent_id" TEXT, "permission_overwrites" TEXT, "position" INTEGER, "rate_limit_per_user" INTEGER, "thread_metadata" TEXT, "topic" TEXT)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "guild_id" = "excluded"."guild_id",
+        "kind" = "excluded"."kind",
+        "name" = "excluded"."name",
+        "nsfw" = "excluded"."nsfw",
+        "parent_id" = "excluded"."parent_id",
+        "permission_overwrites" = "excluded"."permission_overwrites",
+        "position" = "excluded"."position",
+        "rate_limit_per_user" = "excluded"."rate_limit_per_user",
+        "thread_metadata" = "excluded"."thread_metadata",
+        "topic" = "excluded"."topic";
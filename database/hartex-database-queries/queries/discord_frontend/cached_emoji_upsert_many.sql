@@ -0,0 +1,34 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+-- emojis:TEXT
+INSERT INTO "DiscordFrontend"."Nightly"."CachedEmojis" ("animated", "name", "id", "guild_id", "managed")
+SELECT
+    "animated", "name", "id", "guild_id", "managed"
+FROM
+    JSONB_TO_RECORDSET(CAST(:emojis AS JSONB)) AS "Emojis" ("animated" BOOLEAN, "name" TEXT, "id" TEXT, "guild_id" TEXT, "managed" BOOLEAN)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "guild_id" = "excluded"."guild_id",
+        "animated" = "excluded"."animated",
+        "name" = "excluded"."name",
+        "managed" = "excluded"."managed";
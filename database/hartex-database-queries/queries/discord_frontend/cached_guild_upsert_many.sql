@@ -0,0 +1,41 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+-- guilds:TEXT
+INSERT INTO "DiscordFrontend"."Nightly"."CachedGuilds" ("default_message_notifications", "explicit_content_filter", "features", "icon", "large", "name", "owner_id", "id", "mfa_level", "premium_subscription_count", "premium_tier", "verification_level")
+SELECT
+    "default_message_notifications", "explicit_content_filter", "features", "icon", "large", "name", "owner_id", "id", "mfa_level", "premium_subscription_count", "premium_tier", "verification_level"
+FROM
+    JSONB_TO_RECORDSET(CAST(:guilds AS JSONB)) AS "Guilds" ("default_message_notifications" SMALLINT, "explicit_content_filter" SMALLINT, "features" TEXT[], "icon" TEXT, "large" BOOLEAN, "name" TEXT, "owner_id" TEXT, "id" TEXT, "mfa_level" SMALLINT, "premium_subscription_count" BIGINT, "premium_tier" SMALLINT, "verification_level" SMALLINT)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "default_message_notifications" = "excluded"."default_message_notifications",
+        "explicit_content_filter" = "excluded"."explicit_content_filter",
+        "features" = "excluded"."features",
+        "icon" = "excluded"."icon",
+        "large" = "excluded"."large",
+        "mfa_level" = "excluded"."mfa_level",
+        "name" = "excluded"."name",
+        "owner_id" = "excluded"."owner_id",
+        "premium_subscription_count" = "excluded"."premium_subscription_count",
+        "premium_tier" = "excluded"."premium_tier",
+        "verification_level" = "excluded"."verification_level";
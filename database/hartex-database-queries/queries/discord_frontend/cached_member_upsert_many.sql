@@ -0,0 +1,34 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+-- members:TEXT
+INSERT INTO "DiscordFrontend"."Nightly"."CachedMembers" ("flags", "joined_at", "nick", "user_id", "guild_id", "roles")
+SELECT
+    "flags", "joined_at", "nick", "user_id", "guild_id", "roles"
+FROM
+    JSONB_TO_RECORDSET(CAST(:members AS JSONB)) AS "Members" ("flags" BIGINT, "joined_at" TIMESTAMP WITH TIME ZONE, "nick" TEXT, "user_id" TEXT, "guild_id" TEXT, "roles" TEXT[])
+ON CONFLICT ("user_id", "guild_id") DO UPDATE
+    SET
+        "flags" = "excluded"."flags",
+        "joined_at" = "excluded"."joined_at",
+        "nick" = "excluded"."nick",
+        "roles" = "excluded"."roles";
@@ -20,8 +20,8 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
-INSERT INTO "DiscordFrontend"."Nightly"."CachedRoles" ("color", "icon", "id", "guild_id", "flags", "hoist", "managed", "mentionable", "position")
-VALUES (:color, :icon, :id, :guild_id, :flags, :hoist, :managed, :mentionable, :position)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedRoles" ("color", "icon", "id", "guild_id", "flags", "hoist", "managed", "mentionable", "permissions", "position")
+VALUES (:color, :icon, :id, :guild_id, :flags, :hoist, :managed, :mentionable, :permissions, :position)
 ON CONFLICT ("id", "guild_id") DO UPDATE
     SET
         "color" = :color,
@@ -30,4 +30,5 @@ ON CONFLICT ("id", "guild_id") DO UPDATE
         "hoist" = :hoist,
         "managed" = :managed,
         "mentionable" = :mentionable,
+        "permissions" = :permissions,
         "position" = :position;
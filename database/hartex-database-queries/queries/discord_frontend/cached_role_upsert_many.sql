@@ -0,0 +1,38 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+-- roles:TEXT
+INSERT INTO "DiscordFrontend"."Nightly"."CachedRoles" ("color", "icon", "id", "guild_id", "flags", "hoist", "managed", "mentionable", "permissions", "position")
+SELECT
+    "color", "icon", "id", "guild_id", "flags", "hoist", "managed", "mentionable", "permissions", "position"
+FROM
+    JSONB_TO_RECORDSET(CAST(:roles AS JSONB)) AS "Roles" ("color" BIGINT, "icon" TEXT, "id" TEXT, "guild_id" TEXT, "flags" INTEGER, "hoist" BOOLEAN, "managed" BOOLEAN, "mentionable" BOOLEAN, "permissions" BIGINT, "position" INTEGER)
+ON CONFLICT ("id", "guild_id") DO UPDATE
+    SET
+        "color" = "excluded"."color",
+        "icon" = "excluded"."icon",
+        "flags" = "excluded"."flags",
+        "hoist" = "excluded"."hoist",
+        "managed" = "excluded"."managed",
+        "mentionable" = "excluded"."mentionable",
+        "permissions" = "excluded"."permissions",
+        "position" = "excluded"."position";
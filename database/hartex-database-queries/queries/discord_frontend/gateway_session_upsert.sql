@@ -0,0 +1,32 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+INSERT INTO
+    "DiscordFrontend"."Nightly"."GatewaySessions" ("shard_id", "shard_total", "session_id", "resume_url", "sequence", "updated_at")
+VALUES (:shard_id, :shard_total, :session_id, :resume_url, :sequence, :updated_at)
+ON CONFLICT ("shard_id") DO UPDATE
+    SET
+        "shard_total" = :shard_total,
+        "session_id" = :session_id,
+        "resume_url" = :resume_url,
+        "sequence" = :sequence,
+        "updated_at" = :updated_at;
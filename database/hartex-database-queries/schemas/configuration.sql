@@ -24,3 +24,32 @@ CREATE TABLE IF NOT EXISTS "Nightly"."GuildConfigurations" (
     "guild_id" TEXT NOT NULL PRIMARY KEY,
     "enabled_plugins" TEXT[] NOT NULL
 );
+
+CREATE TABLE IF NOT EXISTS "Nightly"."CommandCooldowns" (
+    "guild_id" TEXT NOT NULL,
+    "command" TEXT NOT NULL,
+    "bucket" TEXT NOT NULL,
+    "seconds" INTEGER NOT NULL,
+    PRIMARY KEY("guild_id", "command")
+);
+
+CREATE TABLE IF NOT EXISTS "Nightly"."CommandPermissions" (
+    "guild_id" TEXT NOT NULL,
+    "command" TEXT NOT NULL,
+    "replace_required_permissions" BOOLEAN NOT NULL,
+    PRIMARY KEY("guild_id", "command")
+);
+
+CREATE TABLE IF NOT EXISTS "Nightly"."CommandPermissionRules" (
+    "guild_id" TEXT NOT NULL,
+    "command" TEXT NOT NULL,
+    "kind" TEXT NOT NULL,
+    "target_id" TEXT NOT NULL,
+    "allow" BOOLEAN NOT NULL,
+    PRIMARY KEY("guild_id", "command", "kind", "target_id")
+);
+
+CREATE TABLE IF NOT EXISTS "Nightly"."UtilitiesPluginConfigurations" (
+    "guild_id" TEXT NOT NULL PRIMARY KEY,
+    "reports_channel" TEXT
+);
@@ -22,6 +22,20 @@
 
 CREATE SCHEMA IF NOT EXISTS "Nightly";
 
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedChannels" (
+    "guild_id" TEXT NOT NULL,
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "kind" SMALLINT NOT NULL,
+    "name" TEXT,
+    "nsfw" BOOLEAN,
+    "parent_id" TEXT,
+    "permission_overwrites" TEXT,
+    "position" INTEGER,
+    "rate_limit_per_user" INTEGER,
+    "thread_metadata" TEXT,
+    "topic" TEXT
+);
+
 CREATE TABLE IF NOT EXISTS "Nightly"."CachedEmojis" (
     "id" TEXT NOT NULL PRIMARY KEY,
     "guild_id" TEXT NOT NULL,
@@ -64,6 +78,7 @@ CREATE TABLE IF NOT EXISTS "Nightly"."CachedRoles" (
     "id" TEXT NOT NULL,
     "managed" BOOLEAN NOT NULL,
     "mentionable" BOOLEAN NOT NULL,
+    "permissions" BIGINT NOT NULL,
     "position" INTEGER NOT NULL,
     PRIMARY KEY("guild_id", "id")
 );
@@ -76,3 +91,29 @@ CREATE TABLE IF NOT EXISTS "Nightly"."CachedUsers" (
     "global_name" TEXT,
     "name" TEXT NOT NULL
 );
+
+CREATE TABLE IF NOT EXISTS "Nightly"."ErrorReports" (
+    "hash" TEXT NOT NULL PRIMARY KEY,
+    "kind" TEXT NOT NULL,
+    "report" TEXT NOT NULL,
+    "command" TEXT,
+    "guild_id" TEXT,
+    "user_id" TEXT,
+    "options" TEXT,
+    "timestamp" TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "Nightly"."GatewaySessions" (
+    "shard_id" INTEGER NOT NULL PRIMARY KEY,
+    "shard_total" INTEGER NOT NULL,
+    "session_id" TEXT NOT NULL,
+    "resume_url" TEXT NOT NULL,
+    "sequence" BIGINT NOT NULL,
+    "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "Nightly"."ShardLatencies" (
+    "shard_id" INTEGER NOT NULL PRIMARY KEY,
+    "latency" BIGINT NOT NULL,
+    "updated_at" TIMESTAMP WITH TIME ZONE NOT NULL
+);
@@ -0,0 +1,53 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CommandCooldownUpsertMany {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    cooldowns: String,
+}
+impl CommandCooldownUpsertMany {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(cooldowns: String) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            cooldowns,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("CONFIGURATION_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"Configuration\".\"Nightly\".\"CommandCooldowns\" (\"guild_id\", \"command\", \"bucket\", \"seconds\") SELECT \"guild_id\", \"command\", \"bucket\", \"seconds\" FROM JSONB_TO_RECORDSET(CAST($1 AS JSONB)) AS \"Cooldowns\" (\"guild_id\" TEXT, \"command\" TEXT, \"bucket\" TEXT, \"seconds\" INTEGER) ON CONFLICT(\"guild_id\", \"command\") DO UPDATE SET \"bucket\" = \"excluded\".\"bucket\", \"seconds\" = \"excluded\".\"seconds\"",
+                (self.cooldowns,),
+            )
+            .await
+            .into_crate_result()
+    }
+}
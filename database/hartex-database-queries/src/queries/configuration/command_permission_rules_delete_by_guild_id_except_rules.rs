@@ -0,0 +1,55 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CommandPermissionRulesDeleteByGuildIdExceptRules {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    guild_id: String,
+    rules: Vec<String>,
+}
+impl CommandPermissionRulesDeleteByGuildIdExceptRules {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(guild_id: String, rules: Vec<String>) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            guild_id,
+            rules,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("CONFIGURATION_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "DELETE FROM \"Configuration\".\"Nightly\".\"CommandPermissionRules\" WHERE \"guild_id\" = $1 AND NOT (CONCAT_WS(':', \"command\", \"kind\", \"target_id\") = ANY($2))",
+                (self.guild_id, self.rules),
+            )
+            .await
+            .into_crate_result()
+    }
+}
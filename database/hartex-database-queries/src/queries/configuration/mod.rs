@@ -3,4 +3,16 @@
 // any way.
 // ==================! DO NOT MODIFY !==================
 
+pub mod command_cooldown_delete_by_guild_id_except_commands;
+pub mod command_cooldown_select_by_guild_id_and_command;
+pub mod command_cooldown_upsert_many;
+pub mod command_permission_rules_delete_by_guild_id_except_rules;
+pub mod command_permission_rules_select_by_guild_id_and_command;
+pub mod command_permission_rules_upsert_many;
+pub mod command_permissions_delete_by_guild_id_except_commands;
+pub mod command_permissions_select_by_guild_id_and_command;
+pub mod command_permissions_upsert_many;
 pub mod plugin_enabled;
+pub mod utilities_plugin_configuration_delete_by_guild_id;
+pub mod utilities_plugin_configuration_select_by_guild_id;
+pub mod utilities_plugin_configuration_upsert;
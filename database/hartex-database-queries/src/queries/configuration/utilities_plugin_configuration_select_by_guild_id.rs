@@ -0,0 +1,87 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct UtilitiesPluginConfigurationSelectByGuildId {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    guild_id: String,
+}
+impl UtilitiesPluginConfigurationSelectByGuildId {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(guild_id: String) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            guild_id,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("CONFIGURATION_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn one(
+        self,
+    ) -> crate::result::Result<
+        crate::tables::configuration::NightlyUtilitiesPluginConfigurations,
+    > {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .fetch_with_stmt(
+                "SELECT * FROM \"Configuration\".\"Nightly\".\"UtilitiesPluginConfigurations\" WHERE \"guild_id\" = $1",
+                (self.guild_id,),
+            )
+            .await
+            .into_crate_result()
+            .map(|record| crate::tables::configuration::NightlyUtilitiesPluginConfigurations::try_from(
+                record,
+            ))
+            .flatten()
+    }
+    pub async fn many(
+        self,
+    ) -> crate::result::Result<
+        Vec<crate::tables::configuration::NightlyUtilitiesPluginConfigurations>,
+    > {
+        use itertools::Itertools;
+        use wtx::database::Records;
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .fetch_many_with_stmt(
+                "SELECT * FROM \"Configuration\".\"Nightly\".\"UtilitiesPluginConfigurations\" WHERE \"guild_id\" = $1",
+                (self.guild_id,),
+                |_| Ok::<_, wtx::Error>(()),
+            )
+            .await
+            .into_crate_result()?
+            .iter()
+            .map(|record| crate::tables::configuration::NightlyUtilitiesPluginConfigurations::try_from(
+                record,
+            ))
+            .process_results(|iter| iter.collect_vec())
+    }
+}
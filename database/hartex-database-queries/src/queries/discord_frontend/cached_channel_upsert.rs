@@ -0,0 +1,97 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CachedChannelUpsert {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    guild_id: String,
+    id: String,
+    kind: i16,
+    name: Option<String>,
+    nsfw: Option<bool>,
+    parent_id: Option<String>,
+    permission_overwrites: Option<String>,
+    position: Option<i32>,
+    rate_limit_per_user: Option<i32>,
+    thread_metadata: Option<String>,
+    topic: Option<String>,
+}
+impl CachedChannelUpsert {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(
+        guild_id: String,
+        id: String,
+        kind: i16,
+        name: Option<String>,
+        nsfw: Option<bool>,
+        parent_id: Option<String>,
+        permission_overwrites: Option<String>,
+        position: Option<i32>,
+        rate_limit_per_user: Option<i32>,
+        thread_metadata: Option<String>,
+        topic: Option<String>,
+    ) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            guild_id,
+            id,
+            kind,
+            name,
+            nsfw,
+            parent_id,
+            permission_overwrites,
+            position,
+            rate_limit_per_user,
+            thread_metadata,
+            topic,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedChannels\" (\"guild_id\", \"id\", \"kind\", \"name\", \"nsfw\", \"parent_id\", \"permission_overwrites\", \"position\", \"rate_limit_per_user\", \"thread_metadata\", \"topic\") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) ON CONFLICT(\"id\") DO UPDATE SET \"guild_id\" = $1, \"kind\" = $3, \"name\" = $4, \"nsfw\" = $5, \"parent_id\" = $6, \"permission_overwrites\" = $7, \"position\" = $8, \"rate_limit_per_user\" = $9, \"thread_metadata\" = $10, \"topic\" = $11",
+                (
+                    self.guild_id,
+                    self.id,
+                    self.kind,
+                    self.name,
+                    self.nsfw,
+                    self.parent_id,
+                    self.permission_overwrites,
+                    self.position,
+                    self.rate_limit_per_user,
+                    self.thread_metadata,
+                    self.topic,
+                ),
+            )
+            .await
+            .into_crate_result()
+    }
+}
@@ -0,0 +1,53 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CachedChannelUpsertMany {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    channels: String,
+}
+impl CachedChannelUpsertMany {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(channels: String) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            channels,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedChannels\" (\"guild_id\", \"id\", \"kind\", \"name\", \"nsfw\", \"parent_id\", \"permission_overwrites\", \"position\", \"rate_limit_per_user\", \"thread_metadata\", \"topic\") SELECT \"guild_id\", \"id\", \"kind\", \"name\", \"nsfw\", \"parent_id\", \"permission_overwrites\", \"position\", \"rate_limit_per_user\", \"thread_metadata\", \"topic\" FROM JSONB_TO_RECORDSET(CAST($1 AS JSONB)) AS \"Channels\" (\"guild_id\" TEXT, \"id\" TEXT, \"kind\" SMALLINT, \"name\" TEXT, \"nsfw\" BOOLEAN, \"parent_id\" TEXT, \"permission_overwrites\" TEXT, \"position\" INTEGER, \"rate_limit_per_user\" INTEGER, \"thread_metadata\" TEXT, \"topic\" TEXT) ON CONFLICT(\"id\") DO UPDATE SET \"guild_id\" = \"excluded\".\"guild_id\", \"kind\" = \"excluded\".\"kind\", \"name\" = \"excluded\".\"name\", \"nsfw\" = \"excluded\".\"nsfw\", \"parent_id\" = \"excluded\".\"parent_id\", \"permission_overwrites\" = \"excluded\".\"permission_overwrites\", \"position\" = \"excluded\".\"position\", \"rate_limit_per_user\" = \"excluded\".\"rate_limit_per_user\", \"thread_metadata\" = \"excluded\".\"thread_metadata\", \"topic\" = \"excluded\".\"topic\"",
+                (self.channels,),
+            )
+            .await
+            .into_crate_result()
+    }
+}
@@ -0,0 +1,53 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CachedEmojiUpsertMany {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    emojis: String,
+}
+impl CachedEmojiUpsertMany {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(emojis: String) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            emojis,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\" (\"animated\", \"name\", \"id\", \"guild_id\", \"managed\") SELECT \"animated\", \"name\", \"id\", \"guild_id\", \"managed\" FROM JSONB_TO_RECORDSET(CAST($1 AS JSONB)) AS \"Emojis\" (\"animated\" BOOLEAN, \"name\" TEXT, \"id\" TEXT, \"guild_id\" TEXT, \"managed\" BOOLEAN) ON CONFLICT(\"id\") DO UPDATE SET \"guild_id\" = \"excluded\".\"guild_id\", \"animated\" = \"excluded\".\"animated\", \"name\" = \"excluded\".\"name\", \"managed\" = \"excluded\".\"managed\"",
+                (self.emojis,),
+            )
+            .await
+            .into_crate_result()
+    }
+}
@@ -0,0 +1,97 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CachedGuildUpdateById {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    default_message_notifications: i16,
+    explicit_content_filter: i16,
+    features: Vec<String>,
+    icon: Option<String>,
+    mfa_level: i16,
+    name: String,
+    owner_id: String,
+    premium_subscription_count: Option<i64>,
+    premium_tier: i16,
+    verification_level: i16,
+    id: String,
+}
+impl CachedGuildUpdateById {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(
+        default_message_notifications: i16,
+        explicit_content_filter: i16,
+        features: Vec<String>,
+        icon: Option<String>,
+        mfa_level: i16,
+        name: String,
+        owner_id: String,
+        premium_subscription_count: Option<i64>,
+        premium_tier: i16,
+        verification_level: i16,
+        id: String,
+    ) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            default_message_notifications,
+            explicit_content_filter,
+            features,
+            icon,
+            mfa_level,
+            name,
+            owner_id,
+            premium_subscription_count,
+            premium_tier,
+            verification_level,
+            id,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "UPDATE \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\" SET \"default_message_notifications\" = $1, \"explicit_content_filter\" = $2, \"features\" = $3, \"icon\" = $4, \"mfa_level\" = $5, \"name\" = $6, \"owner_id\" = $7, \"premium_subscription_count\" = $8, \"premium_tier\" = $9, \"verification_level\" = $10 WHERE \"id\" = $11",
+                (
+                    self.default_message_notifications,
+                    self.explicit_content_filter,
+                    self.features,
+                    self.icon,
+                    self.mfa_level,
+                    self.name,
+                    self.owner_id,
+                    self.premium_subscription_count,
+                    self.premium_tier,
+                    self.verification_level,
+                    self.id,
+                ),
+            )
+            .await
+            .into_crate_result()
+    }
+}
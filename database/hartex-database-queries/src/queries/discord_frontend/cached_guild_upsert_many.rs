@@ -0,0 +1,53 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CachedGuildUpsertMany {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    guilds: String,
+}
+impl CachedGuildUpsertMany {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(guilds: String) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            guilds,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\" (\"default_message_notifications\", \"explicit_content_filter\", \"features\", \"icon\", \"large\", \"name\", \"owner_id\", \"id\", \"mfa_level\", \"premium_subscription_count\", \"premium_tier\", \"verification_level\") SELECT \"default_message_notifications\", \"explicit_content_filter\", \"features\", \"icon\", \"large\", \"name\", \"owner_id\", \"id\", \"mfa_level\", \"premium_subscription_count\", \"premium_tier\", \"verification_level\" FROM JSONB_TO_RECORDSET(CAST($1 AS JSONB)) AS \"Guilds\" (\"default_message_notifications\" SMALLINT, \"explicit_content_filter\" SMALLINT, \"features\" TEXT[], \"icon\" TEXT, \"large\" BOOLEAN, \"name\" TEXT, \"owner_id\" TEXT, \"id\" TEXT, \"mfa_level\" SMALLINT, \"premium_subscription_count\" BIGINT, \"premium_tier\" SMALLINT, \"verification_level\" SMALLINT) ON CONFLICT(\"id\") DO UPDATE SET \"default_message_notifications\" = \"excluded\".\"default_message_notifications\", \"explicit_content_filter\" = \"excluded\".\"explicit_content_filter\", \"features\" = \"excluded\".\"features\", \"icon\" = \"excluded\".\"icon\", \"large\" = \"excluded\".\"large\", \"mfa_level\" = \"excluded\".\"mfa_level\", \"name\" = \"excluded\".\"name\", \"owner_id\" = \"excluded\".\"owner_id\", \"premium_subscription_count\" = \"excluded\".\"premium_subscription_count\", \"premium_tier\" = \"excluded\".\"premium_tier\", \"verification_level\" = \"excluded\".\"verification_level\"",
+                (self.guilds,),
+            )
+            .await
+            .into_crate_result()
+    }
+}
@@ -21,6 +21,7 @@ pub struct CachedRoleUpsert {
     hoist: bool,
     managed: bool,
     mentionable: bool,
+    permissions: i64,
     position: i32,
 }
 impl CachedRoleUpsert {
@@ -34,6 +35,7 @@ impl CachedRoleUpsert {
         hoist: bool,
         managed: bool,
         mentionable: bool,
+        permissions: i64,
         position: i32,
     ) -> Self {
         Self {
@@ -48,6 +50,7 @@ impl CachedRoleUpsert {
             hoist,
             managed,
             mentionable,
+            permissions,
             position,
         }
     }
@@ -70,7 +73,7 @@ impl CachedRoleUpsert {
                 ),
             )?
             .execute_with_stmt(
-                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedRoles\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"position\") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) ON CONFLICT(\"id\", \"guild_id\") DO UPDATE SET \"color\" = $1, \"icon\" = $2, \"flags\" = $5, \"hoist\" = $6, \"managed\" = $7, \"mentionable\" = $8, \"position\" = $9",
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedRoles\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"permissions\", \"position\") VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT(\"id\", \"guild_id\") DO UPDATE SET \"color\" = $1, \"icon\" = $2, \"flags\" = $5, \"hoist\" = $6, \"managed\" = $7, \"mentionable\" = $8, \"permissions\" = $9, \"position\" = $10",
                 (
                     self.color,
                     self.icon,
@@ -80,6 +83,7 @@ impl CachedRoleUpsert {
                     self.hoist,
                     self.managed,
                     self.mentionable,
+                    self.permissions,
                     self.position,
                 ),
             )
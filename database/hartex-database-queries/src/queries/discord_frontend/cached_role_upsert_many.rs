@@ -0,0 +1,53 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CachedRoleUpsertMany {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    roles: String,
+}
+impl CachedRoleUpsertMany {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(roles: String) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            roles,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedRoles\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"permissions\", \"position\") SELECT \"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"permissions\", \"position\" FROM JSONB_TO_RECORDSET(CAST($1 AS JSONB)) AS \"Roles\" (\"color\" BIGINT, \"icon\" TEXT, \"id\" TEXT, \"guild_id\" TEXT, \"flags\" INTEGER, \"hoist\" BOOLEAN, \"managed\" BOOLEAN, \"mentionable\" BOOLEAN, \"permissions\" BIGINT, \"position\" INTEGER) ON CONFLICT(\"id\", \"guild_id\") DO UPDATE SET \"color\" = \"excluded\".\"color\", \"icon\" = \"excluded\".\"icon\", \"flags\" = \"excluded\".\"flags\", \"hoist\" = \"excluded\".\"hoist\", \"managed\" = \"excluded\".\"managed\", \"mentionable\" = \"excluded\".\"mentionable\", \"permissions\" = \"excluded\".\"permissions\", \"position\" = \"excluded\".\"position\"",
+                (self.roles,),
+            )
+            .await
+            .into_crate_result()
+    }
+}
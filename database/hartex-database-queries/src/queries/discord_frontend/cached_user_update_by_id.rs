@@ -0,0 +1,67 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CachedUserUpdateById {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    avatar: Option<String>,
+    bot: bool,
+    discriminator: String,
+    name: String,
+    id: String,
+}
+impl CachedUserUpdateById {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(
+        avatar: Option<String>,
+        bot: bool,
+        discriminator: String,
+        name: String,
+        id: String,
+    ) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            avatar,
+            bot,
+            discriminator,
+            name,
+            id,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "UPDATE \"DiscordFrontend\".\"Nightly\".\"CachedUsers\" SET \"avatar\" = $1, \"bot\" = $2, \"discriminator\" = $3, \"name\" = $4 WHERE \"id\" = $5",
+                (self.avatar, self.bot, self.discriminator, self.name, self.id),
+            )
+            .await
+            .into_crate_result()
+    }
+}
@@ -0,0 +1,53 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct CachedUserUpsertMany {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    users: String,
+}
+impl CachedUserUpsertMany {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(users: String) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            users,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedUsers\" (\"avatar\", \"id\", \"bot\", \"name\", \"discriminator\", \"global_name\") SELECT \"avatar\", \"id\", \"bot\", \"name\", \"discriminator\", \"global_name\" FROM JSONB_TO_RECORDSET(CAST($1 AS JSONB)) AS \"Users\" (\"avatar\" TEXT, \"id\" TEXT, \"bot\" BOOLEAN, \"name\" TEXT, \"discriminator\" TEXT, \"global_name\" TEXT) ON CONFLICT(\"id\") DO UPDATE SET \"avatar\" = \"excluded\".\"avatar\", \"bot\" = \"excluded\".\"bot\", \"name\" = \"excluded\".\"name\", \"discriminator\" = \"excluded\".\"discriminator\", \"global_name\" = \"excluded\".\"global_name\"",
+                (self.users,),
+            )
+            .await
+            .into_crate_result()
+    }
+}
@@ -0,0 +1,85 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct ErrorReportInsert {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    hash: String,
+    kind: String,
+    report: String,
+    command: Option<String>,
+    guild_id: Option<String>,
+    user_id: Option<String>,
+    options: Option<String>,
+    timestamp: chrono::DateTime<chrono::offset::Utc>,
+}
+impl ErrorReportInsert {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(
+        hash: String,
+        kind: String,
+        report: String,
+        command: Option<String>,
+        guild_id: Option<String>,
+        user_id: Option<String>,
+        options: Option<String>,
+        timestamp: chrono::DateTime<chrono::offset::Utc>,
+    ) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            hash,
+            kind,
+            report,
+            command,
+            guild_id,
+            user_id,
+            options,
+            timestamp,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"ErrorReports\" (\"hash\", \"kind\", \"report\", \"command\", \"guild_id\", \"user_id\", \"options\", \"timestamp\") VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+                (
+                    self.hash,
+                    self.kind,
+                    self.report,
+                    self.command,
+                    self.guild_id,
+                    self.user_id,
+                    self.options,
+                    self.timestamp,
+                ),
+            )
+            .await
+            .into_crate_result()
+    }
+}
@@ -3,16 +3,64 @@
 // any way.
 // ==================! DO NOT MODIFY !==================
 
+pub mod cached_channel_delete_by_guild_id;
+pub mod cached_channel_delete_by_id;
+pub mod cached_channel_delete_by_parent_id;
+pub mod cached_channel_exists_by_id;
+pub mod cached_channel_select_by_guild_id;
+pub mod cached_channel_select_by_guild_id_paginated;
+pub mod cached_channel_select_by_id;
+pub mod cached_channel_select_by_ids;
+pub mod cached_channel_upsert;
+pub mod cached_channel_upsert_many;
+pub mod cached_emoji_delete_by_guild_id;
+pub mod cached_emoji_delete_by_id;
+pub mod cached_emoji_exists_by_id;
 pub mod cached_emoji_select_by_guild_id;
+pub mod cached_emoji_select_by_guild_id_paginated;
 pub mod cached_emoji_select_by_id;
+pub mod cached_emoji_select_by_ids;
 pub mod cached_emoji_upsert;
+pub mod cached_emoji_upsert_many;
+pub mod cached_guild_delete_by_id;
+pub mod cached_guild_exists_by_id;
 pub mod cached_guild_select_by_id;
+pub mod cached_guild_select_by_ids;
+pub mod cached_guild_select_paginated;
+pub mod cached_guild_update_by_id;
 pub mod cached_guild_upsert;
+pub mod cached_guild_upsert_many;
+pub mod cached_member_delete_by_guild_id;
+pub mod cached_member_delete_by_user_id_and_guild_id;
+pub mod cached_member_exists_by_user_id_and_guild_id;
 pub mod cached_member_select_by_guild_id;
+pub mod cached_member_select_by_guild_id_paginated;
 pub mod cached_member_select_by_user_id_and_guild_id;
+pub mod cached_member_select_by_user_ids_and_guild_ids;
 pub mod cached_member_upsert;
+pub mod cached_member_upsert_many;
+pub mod cached_role_delete_by_guild_id;
+pub mod cached_role_delete_by_id_and_guild_id;
+pub mod cached_role_exists_by_id_and_guild_id;
 pub mod cached_role_select_by_guild_id;
+pub mod cached_role_select_by_guild_id_paginated;
 pub mod cached_role_select_by_id_and_guild_id;
+pub mod cached_role_select_by_ids_and_guild_ids;
 pub mod cached_role_upsert;
+pub mod cached_role_upsert_many;
+pub mod cached_thread_delete_stale_by_guild_id;
+pub mod cached_thread_delete_stale_by_parent_ids;
+pub mod cached_user_delete_by_id;
+pub mod cached_user_exists_by_id;
 pub mod cached_user_select_by_id;
+pub mod cached_user_select_by_ids;
+pub mod cached_user_select_paginated;
+pub mod cached_user_update_by_id;
 pub mod cached_user_upsert;
+pub mod cached_user_upsert_many;
+pub mod error_report_insert;
+pub mod error_report_select_by_hash;
+pub mod gateway_session_select_updated_after;
+pub mod gateway_session_upsert;
+pub mod shard_latency_select_updated_after;
+pub mod shard_latency_upsert;
@@ -0,0 +1,61 @@
+// ==================! DO NOT MODIFY !==================
+// This file is automatically generated by `hartex-database-typedsql`. Please do not modify this in
+// any way.
+// ==================! DO NOT MODIFY !==================
+
+use std::env;
+use tokio::net::TcpStream;
+use wtx::database::Executor as _;
+use wtx::database::client::postgres::Executor;
+use wtx::database::client::postgres::ExecutorBuffer;
+use wtx::misc::Uri;
+use crate::result::IntoCrateResult;
+pub struct ShardLatencyUpsert {
+    db_executor: Option<Executor<wtx::Error, ExecutorBuffer, TcpStream>>,
+    executor_constructor: for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+    shard_id: i32,
+    latency: i64,
+    updated_at: chrono::DateTime<chrono::offset::Utc>,
+}
+impl ShardLatencyUpsert {
+    #[must_use = "Queries must be executed after construction"]
+    pub fn bind(
+        shard_id: i32,
+        latency: i64,
+        updated_at: chrono::DateTime<chrono::offset::Utc>,
+    ) -> Self {
+        Self {
+            db_executor: None,
+            executor_constructor: crate::internal::__internal_executor_constructor
+                as for<'a> fn(Uri<&'a str>) -> crate::internal::Ret<'a>,
+            shard_id,
+            latency,
+            updated_at,
+        }
+    }
+    pub async fn executor(mut self) -> crate::result::Result<Self> {
+        self.db_executor
+            .replace(
+                (self
+                    .executor_constructor)(
+                        Uri::new(&env::var("DISCORD_FRONTEND_PGSQL_URL").unwrap()),
+                    )
+                    .await?,
+            );
+        Ok(self)
+    }
+    pub async fn execute(self) -> crate::result::Result<u64> {
+        self.db_executor
+            .ok_or(
+                crate::result::Error::Generic(
+                    ".executor() has not been called on this query yet",
+                ),
+            )?
+            .execute_with_stmt(
+                "INSERT INTO \"DiscordFrontend\".\"Nightly\".\"ShardLatencies\" (\"shard_id\", \"latency\", \"updated_at\") VALUES ($1, $2, $3) ON CONFLICT(\"shard_id\") DO UPDATE SET \"latency\" = $2, \"updated_at\" = $3",
+                (self.shard_id, self.latency, self.updated_at),
+            )
+            .await
+            .into_crate_result()
+    }
+}
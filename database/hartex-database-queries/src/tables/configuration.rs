@@ -5,6 +5,121 @@
 
 use wtx::database::Record as _;
 use wtx::database::client::postgres::Record;
+pub struct NightlyCommandCooldowns {
+    bucket: String,
+    command: String,
+    guild_id: String,
+    seconds: i32,
+}
+impl NightlyCommandCooldowns {
+    #[must_use]
+    pub fn bucket(&self) -> &str {
+        self.bucket.as_str()
+    }
+    #[must_use]
+    pub fn command(&self) -> &str {
+        self.command.as_str()
+    }
+    #[must_use]
+    pub fn guild_id(&self) -> &str {
+        self.guild_id.as_str()
+    }
+    #[must_use]
+    pub fn seconds(&self) -> i32 {
+        self.seconds
+    }
+}
+impl<'exec, E: From<wtx::Error>> TryFrom<Record<'exec, E>> for NightlyCommandCooldowns
+where
+    crate::result::Error: From<E>,
+{
+    type Error = crate::result::Error;
+    fn try_from(record: Record<'exec, E>) -> crate::result::Result<Self> {
+        Ok(Self {
+            bucket: record.decode("bucket")?,
+            command: record.decode("command")?,
+            guild_id: record.decode("guild_id")?,
+            seconds: record.decode("seconds")?,
+        })
+    }
+}
+pub struct NightlyCommandPermissionRules {
+    allow: bool,
+    command: String,
+    guild_id: String,
+    kind: String,
+    target_id: String,
+}
+impl NightlyCommandPermissionRules {
+    #[must_use]
+    pub fn allow(&self) -> bool {
+        self.allow
+    }
+    #[must_use]
+    pub fn command(&self) -> &str {
+        self.command.as_str()
+    }
+    #[must_use]
+    pub fn guild_id(&self) -> &str {
+        self.guild_id.as_str()
+    }
+    #[must_use]
+    pub fn kind(&self) -> &str {
+        self.kind.as_str()
+    }
+    #[must_use]
+    pub fn target_id(&self) -> &str {
+        self.target_id.as_str()
+    }
+}
+impl<'exec, E: From<wtx::Error>> TryFrom<Record<'exec, E>>
+for NightlyCommandPermissionRules
+where
+    crate::result::Error: From<E>,
+{
+    type Error = crate::result::Error;
+    fn try_from(record: Record<'exec, E>) -> crate::result::Result<Self> {
+        Ok(Self {
+            allow: record.decode("allow")?,
+            command: record.decode("command")?,
+            guild_id: record.decode("guild_id")?,
+            kind: record.decode("kind")?,
+            target_id: record.decode("target_id")?,
+        })
+    }
+}
+pub struct NightlyCommandPermissions {
+    command: String,
+    guild_id: String,
+    replace_required_permissions: bool,
+}
+impl NightlyCommandPermissions {
+    #[must_use]
+    pub fn command(&self) -> &str {
+        self.command.as_str()
+    }
+    #[must_use]
+    pub fn guild_id(&self) -> &str {
+        self.guild_id.as_str()
+    }
+    #[must_use]
+    pub fn replace_required_permissions(&self) -> bool {
+        self.replace_required_permissions
+    }
+}
+impl<'exec, E: From<wtx::Error>> TryFrom<Record<'exec, E>> for NightlyCommandPermissions
+where
+    crate::result::Error: From<E>,
+{
+    type Error = crate::result::Error;
+    fn try_from(record: Record<'exec, E>) -> crate::result::Result<Self> {
+        Ok(Self {
+            command: record.decode("command")?,
+            guild_id: record.decode("guild_id")?,
+            replace_required_permissions: record.decode("replace_required_permissions")?,
+        })
+    }
+}
 pub struct NightlyGuildConfigurations {
     enabled_plugins: Vec<String>,
     guild_id: String,
@@ -31,3 +146,30 @@ where
         })
     }
 }
+pub struct NightlyUtilitiesPluginConfigurations {
+    guild_id: String,
+    reports_channel: Option<String>,
+}
+impl NightlyUtilitiesPluginConfigurations {
+    #[must_use]
+    pub fn guild_id(&self) -> &str {
+        self.guild_id.as_str()
+    }
+    #[must_use]
+    pub fn reports_channel(&self) -> Option<&str> {
+        self.reports_channel.as_deref()
+    }
+}
+impl<'exec, E: From<wtx::Error>> TryFrom<Record<'exec, E>>
+for NightlyUtilitiesPluginConfigurations
+where
+    crate::result::Error: From<E>,
+{
+    type Error = crate::result::Error;
+    fn try_from(record: Record<'exec, E>) -> crate::result::Result<Self> {
+        Ok(Self {
+            guild_id: record.decode("guild_id")?,
+            reports_channel: record.decode_opt("reports_channel")?,
+        })
+    }
+}
@@ -5,6 +5,86 @@
 
 use wtx::database::Record as _;
 use wtx::database::client::postgres::Record;
+pub struct NightlyCachedChannels {
+    guild_id: String,
+    id: String,
+    kind: i16,
+    name: Option<String>,
+    nsfw: Option<bool>,
+    parent_id: Option<String>,
+    permission_overwrites: Option<String>,
+    position: Option<i32>,
+    rate_limit_per_user: Option<i32>,
+    thread_metadata: Option<String>,
+    topic: Option<String>,
+}
+impl NightlyCachedChannels {
+    #[must_use]
+    pub fn guild_id(&self) -> &str {
+        self.guild_id.as_str()
+    }
+    #[must_use]
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+    #[must_use]
+    pub fn kind(&self) -> i16 {
+        self.kind
+    }
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    #[must_use]
+    pub fn nsfw(&self) -> Option<bool> {
+        self.nsfw
+    }
+    #[must_use]
+    pub fn parent_id(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+    #[must_use]
+    pub fn permission_overwrites(&self) -> Option<&str> {
+        self.permission_overwrites.as_deref()
+    }
+    #[must_use]
+    pub fn position(&self) -> Option<i32> {
+        self.position
+    }
+    #[must_use]
+    pub fn rate_limit_per_user(&self) -> Option<i32> {
+        self.rate_limit_per_user
+    }
+    #[must_use]
+    pub fn thread_metadata(&self) -> Option<&str> {
+        self.thread_metadata.as_deref()
+    }
+    #[must_use]
+    pub fn topic(&self) -> Option<&str> {
+        self.topic.as_deref()
+    }
+}
+impl<'exec, E: From<wtx::Error>> TryFrom<Record<'exec, E>> for NightlyCachedChannels
+where
+    crate::result::Error: From<E>,
+{
+    type Error = crate::result::Error;
+    fn try_from(record: Record<'exec, E>) -> crate::result::Result<Self> {
+        Ok(Self {
+            guild_id: record.decode("guild_id")?,
+            id: record.decode("id")?,
+            kind: record.decode("kind")?,
+            name: record.decode_opt("name")?,
+            nsfw: record.decode_opt("nsfw")?,
+            parent_id: record.decode_opt("parent_id")?,
+            permission_overwrites: record.decode_opt("permission_overwrites")?,
+            position: record.decode_opt("position")?,
+            rate_limit_per_user: record.decode_opt("rate_limit_per_user")?,
+            thread_metadata: record.decode_opt("thread_metadata")?,
+            topic: record.decode_opt("topic")?,
+        })
+    }
+}
 pub struct NightlyCachedEmojis {
     animated: bool,
     guild_id: String,
@@ -195,6 +275,7 @@ pub struct NightlyCachedRoles {
     id: String,
     managed: bool,
     mentionable: bool,
+    permissions: i64,
     position: i32,
 }
 impl NightlyCachedRoles {
@@ -231,6 +312,10 @@ impl NightlyCachedRoles {
         self.mentionable
     }
     #[must_use]
+    pub fn permissions(&self) -> i64 {
+        self.permissions
+    }
+    #[must_use]
     pub fn position(&self) -> i32 {
         self.position
     }
@@ -250,6 +335,7 @@ where
             id: record.decode("id")?,
             managed: record.decode("managed")?,
             mentionable: record.decode("mentionable")?,
+            permissions: record.decode("permissions")?,
             position: record.decode("position")?,
         })
     }
@@ -304,3 +390,147 @@ where
         })
     }
 }
+pub struct NightlyErrorReports {
+    command: Option<String>,
+    guild_id: Option<String>,
+    hash: String,
+    kind: String,
+    options: Option<String>,
+    report: String,
+    timestamp: chrono::DateTime<chrono::offset::Utc>,
+    user_id: Option<String>,
+}
+impl NightlyErrorReports {
+    #[must_use]
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+    #[must_use]
+    pub fn guild_id(&self) -> Option<&str> {
+        self.guild_id.as_deref()
+    }
+    #[must_use]
+    pub fn hash(&self) -> &str {
+        self.hash.as_str()
+    }
+    #[must_use]
+    pub fn kind(&self) -> &str {
+        self.kind.as_str()
+    }
+    #[must_use]
+    pub fn options(&self) -> Option<&str> {
+        self.options.as_deref()
+    }
+    #[must_use]
+    pub fn report(&self) -> &str {
+        self.report.as_str()
+    }
+    #[must_use]
+    pub fn timestamp(&self) -> chrono::DateTime<chrono::offset::Utc> {
+        self.timestamp
+    }
+    #[must_use]
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_id.as_deref()
+    }
+}
+impl<'exec, E: From<wtx::Error>> TryFrom<Record<'exec, E>> for NightlyErrorReports
+where
+    crate::result::Error: From<E>,
+{
+    type Error = crate::result::Error;
+    fn try_from(record: Record<'exec, E>) -> crate::result::Result<Self> {
+        Ok(Self {
+            command: record.decode_opt("command")?,
+            guild_id: record.decode_opt("guild_id")?,
+            hash: record.decode("hash")?,
+            kind: record.decode("kind")?,
+            options: record.decode_opt("options")?,
+            report: record.decode("report")?,
+            timestamp: record.decode("timestamp")?,
+            user_id: record.decode_opt("user_id")?,
+        })
+    }
+}
+pub struct NightlyGatewaySessions {
+    resume_url: String,
+    sequence: i64,
+    session_id: String,
+    shard_id: i32,
+    shard_total: i32,
+    updated_at: chrono::DateTime<chrono::offset::Utc>,
+}
+impl NightlyGatewaySessions {
+    #[must_use]
+    pub fn resume_url(&self) -> &str {
+        self.resume_url.as_str()
+    }
+    #[must_use]
+    pub fn sequence(&self) -> i64 {
+        self.sequence
+    }
+    #[must_use]
+    pub fn session_id(&self) -> &str {
+        self.session_id.as_str()
+    }
+    #[must_use]
+    pub fn shard_id(&self) -> i32 {
+        self.shard_id
+    }
+    #[must_use]
+    pub fn shard_total(&self) -> i32 {
+        self.shard_total
+    }
+    #[must_use]
+    pub fn updated_at(&self) -> chrono::DateTime<chrono::offset::Utc> {
+        self.updated_at
+    }
+}
+impl<'exec, E: From<wtx::Error>> TryFrom<Record<'exec, E>> for NightlyGatewaySessions
+where
+    crate::result::Error: From<E>,
+{
+    type Error = crate::result::Error;
+    fn try_from(record: Record<'exec, E>) -> crate::result::Result<Self> {
+        Ok(Self {
+            resume_url: record.decode("resume_url")?,
+            sequence: record.decode("sequence")?,
+            session_id: record.decode("session_id")?,
+            shard_id: record.decode("shard_id")?,
+            shard_total: record.decode("shard_total")?,
+            updated_at: record.decode("updated_at")?,
+        })
+    }
+}
+pub struct NightlyShardLatencies {
+    latency: i64,
+    shard_id: i32,
+    updated_at: chrono::DateTime<chrono::offset::Utc>,
+}
+impl NightlyShardLatencies {
+    #[must_use]
+    pub fn latency(&self) -> i64 {
+        self.latency
+    }
+    #[must_use]
+    pub fn shard_id(&self) -> i32 {
+        self.shard_id
+    }
+    #[must_use]
+    pub fn updated_at(&self) -> chrono::DateTime<chrono::offset::Utc> {
+        self.updated_at
+    }
+}
+impl<'exec, E: From<wtx::Error>> TryFrom<Record<'exec, E>> for NightlyShardLatencies
+where
+    crate::result::Error: From<E>,
+{
+    type Error = crate::result::Error;
+    fn try_from(record: Record<'exec, E>) -> crate::result::Result<Self> {
+        Ok(Self {
+            latency: record.decode("latency")?,
+            shard_id: record.decode("shard_id")?,
+            updated_at: record.decode("updated_at")?,
+        })
+    }
+}
@@ -40,9 +40,11 @@ use crate::codegen::DO_NOT_MODIFY_HEADER;
 use crate::codegen::types;
 use crate::query::QueryInfo;
 use crate::query::QueryInfoInner;
+use crate::query::delete::DeleteQueryInfo;
 use crate::query::insert::InsertQueryInfo;
 use crate::query::select::SelectQueryInfo;
 use crate::query::select::SelectWhat;
+use crate::query::update::UpdateQueryInfo;
 
 pub(crate) fn generate_query_structs_from_queries<P>(
     query_map: BTreeMap<String, QueryInfo>,
@@ -110,6 +112,10 @@ fn generate_query_struct_token_stream(
     let structname = Ident::new(name.to_case(Case::Pascal).as_str(), Span::call_site());
 
     let (table, placeholders) = match query.inner.clone() {
+        QueryInfoInner::Delete(DeleteQueryInfo {
+            from_table,
+            placeholders,
+        }) => (from_table, placeholders),
         QueryInfoInner::Insert(InsertQueryInfo {
             into_table,
             placeholders,
@@ -130,6 +136,10 @@ fn generate_query_struct_token_stream(
                 ),
             ..
         }) => (table.clone(), placeholders.clone()),
+        QueryInfoInner::Update(UpdateQueryInfo {
+            table,
+            placeholders,
+        }) => (table, placeholders),
         _ => return Err(crate::error::Error::QueryFile("unsupported query type")),
     };
 
@@ -227,27 +237,32 @@ fn generate_query_fns_token_streams(
     schema: &str,
 ) -> Vec<TokenStream> {
     match query_info.inner {
+        QueryInfoInner::Delete(delete) => {
+            generate_execute_query_fn_token_stream(&delete.placeholders, &query_info.raw)
+        }
         QueryInfoInner::Insert(insert) => {
-            generate_insert_query_fn_token_stream(&insert, &query_info.raw)
+            generate_execute_query_fn_token_stream(&insert.placeholders, &query_info.raw)
         }
         QueryInfoInner::Select(select) => {
             generate_select_query_fns_token_streams(&select, &query_info.raw, schema)
         }
+        QueryInfoInner::Update(update) => {
+            generate_execute_query_fn_token_stream(&update.placeholders, &query_info.raw)
+        }
     }
 }
 
-fn generate_insert_query_fn_token_stream(
-    insert: &InsertQueryInfo,
+fn generate_execute_query_fn_token_stream(
+    placeholders: &[String],
     raw: &Statement,
 ) -> Vec<TokenStream> {
     let mut rawstr = raw.to_string();
-    for (i, placeholder) in insert.placeholders.iter().enumerate() {
+    for (i, placeholder) in placeholders.iter().enumerate() {
         rawstr = rawstr.replace(&format!(":{placeholder}"), &format!("${}", i + 1));
     }
     let stmt = Literal::string(rawstr.as_str());
 
-    let placeholders = insert
-        .placeholders
+    let placeholders = placeholders
         .iter()
         .map(|placeholder| Ident::new(placeholder, Span::call_site()))
         .map(|ident| quote::quote! {self.#ident})
@@ -0,0 +1,64 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::BTreeMap;
+
+use sqlparser::ast::Delete;
+use sqlparser::ast::FromTable;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+use sqlparser::ast::Visit;
+
+use crate::schema::SchemaInfo;
+use crate::schema::TableInfo;
+use crate::visitor::PlaceholderVisitor;
+
+#[derive(Clone, Debug)]
+pub(crate) struct DeleteQueryInfo {
+    pub(crate) from_table: TableInfo,
+    pub(crate) placeholders: Vec<String>,
+}
+
+pub(crate) fn parse_delete_query(
+    delete: &Delete,
+    schema_infos: &BTreeMap<String, SchemaInfo>,
+) -> crate::error::Result<DeleteQueryInfo> {
+    let (FromTable::WithFromKeyword(tables) | FromTable::WithoutKeyword(tables)) = &delete.from;
+    let Some(TableWithJoins {
+        relation: TableFactor::Table { name, .. },
+        ..
+    }) = tables.first()
+    else {
+        return Err(crate::error::Error::QueryFile(
+            "delete queries must delete from a table",
+        ));
+    };
+    let from_table = super::table_info(name, schema_infos)?;
+
+    let mut plvisit = PlaceholderVisitor::default();
+    delete.visit(&mut plvisit);
+
+    Ok(DeleteQueryInfo {
+        from_table,
+        placeholders: plvisit.placeholders,
+    })
+}
@@ -26,8 +26,11 @@ use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 
+use convert_case::Case;
+use convert_case::Casing;
 use itertools::Itertools;
 use sqlparser::ast::DataType;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
 use sqlparser::ast::SetExpr;
 use sqlparser::ast::Statement;
@@ -36,16 +39,21 @@ use walkdir::WalkDir;
 
 use crate::POSTGRESQL_DIALECT;
 use crate::schema::SchemaInfo;
+use crate::schema::TableInfo;
 
+pub(crate) mod delete;
 pub(crate) mod insert;
 pub(crate) mod select;
 mod types;
+pub(crate) mod update;
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub(crate) enum QueryInfoInner {
+    Delete(delete::DeleteQueryInfo),
     Insert(insert::InsertQueryInfo),
     Select(select::SelectQueryInfo),
+    Update(update::UpdateQueryInfo),
 }
 
 #[derive(Clone, Debug)]
@@ -102,15 +110,23 @@ pub(crate) fn parse_query(
     let parent = path.components().next_back().unwrap().as_os_str();
 
     let inner = match statement.clone() {
+        Statement::Delete(delete) => {
+            QueryInfoInner::Delete(delete::parse_delete_query(&delete, schema_map)?)
+        }
         Statement::Insert(insert) => {
             QueryInfoInner::Insert(insert::parse_insert_query(&insert, schema_map)?)
         }
         Statement::Query(
-            deref!(Query {
+            ref query @ deref!(Query {
                 body: deref!(SetExpr::Select(deref!(ref select))),
                 ..
             }),
-        ) => QueryInfoInner::Select(select::parse_select_query(select, schema_map)?),
+        ) => QueryInfoInner::Select(select::parse_select_query_with_modifiers(
+            query, select, schema_map,
+        )?),
+        update @ Statement::Update { .. } => {
+            QueryInfoInner::Update(update::parse_update_query(&update, schema_map)?)
+        }
         _ => return Err(crate::error::Error::QueryFile("unsupported query type")),
     };
 
@@ -135,3 +151,24 @@ pub(crate) fn parse_query(
         extra_placeholder_tys,
     }))
 }
+
+pub(crate) fn table_info(
+    name: &ObjectName,
+    schema_infos: &BTreeMap<String, SchemaInfo>,
+) -> crate::error::Result<TableInfo> {
+    let schema_name = name
+        .0
+        .first()
+        .ok_or(crate::error::Error::QueryFile("schema name not found"))?;
+    let key = schema_name.value.to_case(Case::Snake);
+    let schema_info = schema_infos
+        .get(&key)
+        .ok_or(crate::error::Error::QueryFile("schema not found"))?;
+
+    let table_key = ObjectName(name.0[1..].to_vec()).to_string();
+    schema_info
+        .tables
+        .get(&table_key)
+        .cloned()
+        .ok_or(crate::error::Error::QueryFile("table not found in schema"))
+}
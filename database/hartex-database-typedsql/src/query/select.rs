@@ -106,3 +106,19 @@ pub(crate) fn parse_select_query(
         placeholders: plvisit.placeholders,
     })
 }
+
+/// Parses a select query, including placeholders outside of its body such as in `LIMIT` and
+/// `OFFSET` clauses.
+pub(crate) fn parse_select_query_with_modifiers(
+    query: &Query,
+    select: &Select,
+    schema_infos: &BTreeMap<String, SchemaInfo>,
+) -> crate::error::Result<SelectQueryInfo> {
+    let mut info = parse_select_query(select, schema_infos)?;
+
+    let mut plvisit = PlaceholderVisitor::default();
+    query.visit(&mut plvisit);
+    info.placeholders = plvisit.placeholders;
+
+    Ok(info)
+}
@@ -19,11 +19,17 @@
  * You should have received a copy of the GNU Affero General Public License along
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
+use sqlparser::ast::ArrayElemTypeDef;
 use sqlparser::ast::DataType;
 
 pub(crate) fn str_to_sql_data_type(string: &str) -> Option<DataType> {
     Some(match string {
+        "BIGINT" => DataType::BigInt(None),
         "TEXT" => DataType::Text,
+        "TEXT[]" => DataType::Array(ArrayElemTypeDef::SquareBracket(
+            Box::new(DataType::Text),
+            None,
+        )),
         _ => return None,
     })
 }
@@ -0,0 +1,66 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::BTreeMap;
+
+use sqlparser::ast::Statement;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+use sqlparser::ast::Visit;
+
+use crate::schema::SchemaInfo;
+use crate::schema::TableInfo;
+use crate::visitor::PlaceholderVisitor;
+
+#[derive(Clone, Debug)]
+pub(crate) struct UpdateQueryInfo {
+    pub(crate) table: TableInfo,
+    pub(crate) placeholders: Vec<String>,
+}
+
+pub(crate) fn parse_update_query(
+    update: &Statement,
+    schema_infos: &BTreeMap<String, SchemaInfo>,
+) -> crate::error::Result<UpdateQueryInfo> {
+    let Statement::Update {
+        table:
+            TableWithJoins {
+                relation: TableFactor::Table { name, .. },
+                ..
+            },
+        ..
+    } = update
+    else {
+        return Err(crate::error::Error::QueryFile(
+            "update queries must update a table",
+        ));
+    };
+    let table = super::table_info(name, schema_infos)?;
+
+    let mut plvisit = PlaceholderVisitor::default();
+    update.visit(&mut plvisit);
+
+    Ok(UpdateQueryInfo {
+        table,
+        placeholders: plvisit.placeholders,
+    })
+}
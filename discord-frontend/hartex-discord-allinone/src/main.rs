@@ -0,0 +1,66 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # All-in-One Process
+//!
+//! The all-in-one process runs the leader, the worker and the entitycache update service within
+//! a single process, exchanging records over the in-process transport instead of Kafka. This is
+//! intended for local development and integration tests.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use hartex_discord_core::dotenvy;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::signal;
+use hartex_discord_transport::Transport;
+use hartex_discord_transport::inprocess::InProcessTransport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+/// Entry point.
+#[allow(clippy::large_futures)]
+#[tokio::main(flavor = "multi_thread")]
+pub async fn main() -> miette::Result<()> {
+    hartex_log::initialize();
+
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let transport = Transport::InProcess(InProcessTransport::new());
+    transport.install()?;
+
+    // the worker and the entitycache service only return once their channels are closed, which
+    // never happens with the in-process transport; the leader is the one that shuts down
+    tokio::select! {
+        result = hartex_discord_leader::run(transport.clone(), async {
+            if let Err(error) = signal::ctrl_c().await {
+                log::error!("failed to listen for ctrl-c signal: {error}");
+            }
+
+            log::warn!("ctrl-c signal received, shutting down");
+        }) => result,
+        result = hartex_discord_worker::run(transport.clone()) => result,
+        result = hartex_discord_entitycache_service::run(transport) => result,
+    }
+}
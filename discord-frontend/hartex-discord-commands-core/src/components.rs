@@ -0,0 +1,150 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Components
+//!
+//! Message components (buttons and select menus) are routed back to the command that created
+//! them through a namespaced custom ID of the form `plugin:command:action:state`.
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// The separator between the segments of a custom ID.
+const SEPARATOR: char = ':';
+
+/// A namespaced custom ID of a message component.
+///
+/// The `state` segment is optional and may itself contain the separator, which allows commands
+/// to carry arbitrary state (for example the current page of a paginated embed) in the component.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomId {
+    /// The name of the plugin the component belongs to.
+    pub plugin: String,
+    /// The name of the command the component belongs to.
+    pub command: String,
+    /// The action to perform when the component is interacted with.
+    pub action: String,
+    /// The state attached to the component.
+    pub state: Option<String>,
+}
+
+impl CustomId {
+    /// Create a new custom ID without any state.
+    pub fn new(
+        plugin: impl Into<String>,
+        command: impl Into<String>,
+        action: impl Into<String>,
+    ) -> Self {
+        Self {
+            plugin: plugin.into(),
+            command: command.into(),
+            action: action.into(),
+            state: None,
+        }
+    }
+
+    /// Attach state to the custom ID.
+    #[must_use]
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state.replace(state.into());
+        self
+    }
+}
+
+impl Display for CustomId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{SEPARATOR}{}{SEPARATOR}{}",
+            self.plugin, self.command, self.action
+        )?;
+
+        if let Some(state) = &self.state {
+            write!(f, "{SEPARATOR}{state}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for CustomId {
+    type Err = CustomIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = s.splitn(4, SEPARATOR);
+
+        let (Some(plugin), Some(command), Some(action)) =
+            (segments.next(), segments.next(), segments.next())
+        else {
+            return Err(CustomIdError {
+                kind: CustomIdErrorKind::MissingSegment,
+            });
+        };
+
+        if [plugin, command, action]
+            .iter()
+            .any(|segment| segment.is_empty())
+        {
+            return Err(CustomIdError {
+                kind: CustomIdErrorKind::EmptySegment,
+            });
+        }
+
+        Ok(Self {
+            plugin: plugin.to_string(),
+            command: command.to_string(),
+            action: action.to_string(),
+            state: segments.next().map(String::from),
+        })
+    }
+}
+
+/// Custom ID parsing error.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct CustomIdError {
+    /// The error type.
+    pub kind: CustomIdErrorKind,
+}
+
+impl Display for CustomIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            CustomIdErrorKind::EmptySegment => f.write_str("custom id contains an empty segment"),
+            CustomIdErrorKind::MissingSegment => {
+                f.write_str("custom id is not in the form of `plugin:command:action:state`")
+            }
+        }
+    }
+}
+
+impl Error for CustomIdError {}
+
+/// The type of custom ID parsing error that has occurred.
+#[derive(Clone, Debug)]
+pub enum CustomIdErrorKind {
+    EmptySegment,
+    MissingSegment,
+}
@@ -0,0 +1,114 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Cooldowns
+//!
+//! Cooldowns limit how often a command can be invoked within a bucket, which is either the invoking
+//! user, the channel or the guild the command is invoked in.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+pub use hartex_discord_configuration_models::cooldowns::CooldownBucket;
+
+/// The cooldown of a command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cooldown {
+    /// The bucket the cooldown is tracked in.
+    pub bucket: CooldownBucket,
+    /// The duration of the cooldown.
+    pub duration: Duration,
+}
+
+impl Cooldown {
+    /// Create a new cooldown.
+    #[must_use]
+    pub const fn new(bucket: CooldownBucket, duration: Duration) -> Self {
+        Self { bucket, duration }
+    }
+}
+
+/// Tracks when the cooldowns of commands expire.
+///
+/// Cooldowns are only tracked in memory, within the process the tracker lives in. Expired cooldowns
+/// are pruned in the order they expire in whenever a cooldown is triggered.
+#[derive(Default)]
+pub struct CooldownTracker {
+    expirations: Mutex<CooldownExpirations>,
+}
+
+/// The cooldowns tracked by a cooldown tracker.
+#[derive(Default)]
+struct CooldownExpirations {
+    map: HashMap<CooldownKey, Instant>,
+    /// The cooldowns ordered by when they expire.
+    queue: BinaryHeap<Reverse<(Instant, CooldownKey)>>,
+}
+
+/// The command, bucket and bucket key a cooldown is tracked for.
+type CooldownKey = (String, CooldownBucket, u64);
+
+impl CooldownTracker {
+    /// Create a new cooldown tracker.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts the cooldown of a command in the given bucket unless it is already active, in which
+    /// case the time remaining until it expires is returned instead.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn trigger(&self, command: &str, cooldown: Cooldown, key: u64) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut expirations = self.expirations.lock().unwrap();
+        expirations.prune(now);
+
+        let entry = (command.to_string(), cooldown.bucket, key);
+        if let Some(expiration) = expirations.map.get(&entry) {
+            return Err(*expiration - now);
+        }
+
+        let expiration = now + cooldown.duration;
+        expirations.map.insert(entry.clone(), expiration);
+        expirations.queue.push(Reverse((expiration, entry)));
+
+        Ok(())
+    }
+}
+
+impl CooldownExpirations {
+    /// Drops every cooldown that has expired by the given time.
+    fn prune(&mut self, now: Instant) {
+        while let Some(Reverse((expiration, _))) = self.queue.peek()
+            && *expiration <= now
+        {
+            if let Some(Reverse((_, entry))) = self.queue.pop() {
+                self.map.remove(&entry);
+            }
+        }
+    }
+}
@@ -25,6 +25,7 @@
 //! This crate provides core traits for the command system and reexports useful macros.
 
 #![allow(incomplete_features)]
+#![feature(let_chains)]
 #![deny(clippy::pedantic)]
 #![deny(unsafe_code)]
 #![deny(warnings)]
@@ -36,4 +37,10 @@ extern crate hartex_discord_commands_macros;
 #[cfg(feature = "derive")]
 pub use hartex_discord_commands_macros::*;
 
+pub mod components;
+pub mod cooldowns;
+pub mod permissions;
+pub mod registry;
+pub mod response;
+pub mod targets;
 pub mod traits;
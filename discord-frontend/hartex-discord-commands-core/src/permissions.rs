@@ -0,0 +1,219 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Permissions
+//!
+//! Guilds may allow or deny commands for specific roles, users and channels in their
+//! configuration. These rules are evaluated in addition to, or instead of, the permissions a
+//! command requires by default.
+
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::guild::Permissions;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use miette::IntoDiagnostic;
+
+/// The target of a permission rule.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PermissionTarget {
+    /// The rule targets a role.
+    Role(Id<RoleMarker>),
+    /// The rule targets a user.
+    User(Id<UserMarker>),
+    /// The rule targets a channel.
+    Channel(Id<ChannelMarker>),
+}
+
+/// A rule allowing or denying a command for a specific target.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PermissionRule {
+    /// The target of the rule.
+    pub target: PermissionTarget,
+    /// Whether the rule allows or denies the command.
+    pub allow: bool,
+}
+
+/// The member a command is checked against.
+#[derive(Clone, Debug)]
+pub struct PermissionSubject {
+    /// The ID of the user.
+    pub user_id: Id<UserMarker>,
+    /// The roles of the member, including the `@everyone` role.
+    pub roles: Vec<Id<RoleMarker>>,
+    /// The channel the command is invoked in.
+    pub channel_id: Option<Id<ChannelMarker>>,
+    /// The permissions of the member in the channel.
+    pub permissions: Permissions,
+}
+
+impl PermissionSubject {
+    /// Create a permission subject from the invoking member of an interaction, if invoked in a
+    /// guild.
+    #[must_use]
+    pub fn from_interaction(interaction: &Interaction) -> Option<Self> {
+        let guild_id = interaction.guild_id?;
+        let member = interaction.member.as_ref()?;
+
+        Some(Self {
+            user_id: interaction.author_id()?,
+            roles: Self::roles_with_everyone(guild_id, &member.roles),
+            channel_id: interaction.channel.as_ref().map(|channel| channel.id),
+            permissions: member.permissions.unwrap_or_else(Permissions::empty),
+        })
+    }
+
+    /// Returns the given roles together with the `@everyone` role of the guild, whose ID is the
+    /// same as that of the guild.
+    #[must_use]
+    pub fn roles_with_everyone(
+        guild_id: Id<GuildMarker>,
+        roles: &[Id<RoleMarker>],
+    ) -> Vec<Id<RoleMarker>> {
+        let mut roles = roles.to_vec();
+        roles.push(guild_id.cast());
+
+        roles
+    }
+}
+
+/// The outcome of checking whether a command may be run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PermissionCheck {
+    /// The command is allowed by a rule.
+    AllowedByRule(PermissionTarget),
+    /// The command is denied by a rule.
+    DeniedByRule(PermissionTarget),
+    /// The command is allowed as the permissions it requires are held.
+    AllowedByPermissions,
+    /// The command is denied as some of the permissions it requires are missing.
+    DeniedByPermissions(Permissions),
+}
+
+impl PermissionCheck {
+    /// Whether the command is allowed.
+    #[must_use]
+    pub fn allowed(self) -> bool {
+        matches!(self, Self::AllowedByRule(_) | Self::AllowedByPermissions)
+    }
+}
+
+/// The permission overrides of a command in a guild.
+#[derive(Clone, Debug, Default)]
+pub struct CommandPermissions {
+    /// Whether an allowing rule replaces the permissions the command requires by default, instead
+    /// of being checked in addition to them.
+    pub replace_required_permissions: bool,
+    /// The permission rules of the command.
+    pub rules: Vec<PermissionRule>,
+}
+
+impl CommandPermissions {
+    /// Loads the permission overrides of a command configured in a guild.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn load(guild_id: Id<GuildMarker>, command: &str) -> miette::Result<Self> {
+        let replace_required_permissions =
+            ConfigurationProvider::command_permissions(guild_id, command)
+                .await?
+                .is_some_and(|permissions| permissions.replace_required_permissions());
+
+        let rules = ConfigurationProvider::command_permission_rules(guild_id, command)
+            .await?
+            .into_iter()
+            .map(|rule| {
+                let target = match rule.kind() {
+                    "role" => PermissionTarget::Role(rule.target_id().parse().into_diagnostic()?),
+                    "user" => PermissionTarget::User(rule.target_id().parse().into_diagnostic()?),
+                    "channel" => {
+                        PermissionTarget::Channel(rule.target_id().parse().into_diagnostic()?)
+                    }
+                    kind => miette::bail!("unknown permission rule kind: {kind}"),
+                };
+
+                Ok(PermissionRule {
+                    target,
+                    allow: rule.allow(),
+                })
+            })
+            .collect::<miette::Result<_>>()?;
+
+        Ok(Self {
+            replace_required_permissions,
+            rules,
+        })
+    }
+
+    /// Checks whether a command requiring the given permissions may be run by a subject.
+    ///
+    /// A rule denying the channel always takes precedence. Otherwise, a rule for the user takes
+    /// precedence over rules for roles, among which allowing rules take precedence over denying
+    /// ones, followed by a rule allowing the channel.
+    ///
+    /// An allowing rule replaces the permissions the command requires if configured to, and is
+    /// otherwise only checked in addition to them.
+    #[must_use]
+    pub fn evaluate(&self, subject: &PermissionSubject, required: Permissions) -> PermissionCheck {
+        let channel_rule = self.rules.iter().find(|rule| {
+            subject
+                .channel_id
+                .is_some_and(|channel_id| rule.target == PermissionTarget::Channel(channel_id))
+        });
+        if let Some(rule) = channel_rule
+            && !rule.allow
+        {
+            return PermissionCheck::DeniedByRule(rule.target);
+        }
+
+        let user_rule = self
+            .rules
+            .iter()
+            .find(|rule| rule.target == PermissionTarget::User(subject.user_id));
+        let role_rules = self
+            .rules
+            .iter()
+            .filter(|rule| {
+                subject
+                    .roles
+                    .iter()
+                    .any(|role_id| rule.target == PermissionTarget::Role(*role_id))
+            })
+            .collect::<Vec<_>>();
+        let role_rule = role_rules
+            .iter()
+            .find(|rule| rule.allow)
+            .or_else(|| role_rules.first())
+            .copied();
+
+        let missing = required - subject.permissions;
+        match user_rule.or(role_rule).or(channel_rule) {
+            Some(rule) if !rule.allow => PermissionCheck::DeniedByRule(rule.target),
+            Some(rule) if self.replace_required_permissions || missing.is_empty() => {
+                PermissionCheck::AllowedByRule(rule.target)
+            }
+            _ if !missing.is_empty() => PermissionCheck::DeniedByPermissions(missing),
+            _ => PermissionCheck::AllowedByPermissions,
+        }
+    }
+}
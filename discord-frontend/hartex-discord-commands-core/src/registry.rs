@@ -0,0 +1,64 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Registry
+//!
+//! Commands declared with the `#[command]` macro register themselves in a distributed registry,
+//! such that they need not be listed by hand anywhere else.
+
+use hartex_discord_core::discord::model::gateway::Intents;
+#[doc(hidden)]
+pub use inventory;
+
+use crate::traits::Command;
+
+/// The registration of a command in the registry.
+pub struct CommandRegistration {
+    constructor: fn() -> Box<dyn Command + Send + Sync>,
+}
+
+impl CommandRegistration {
+    /// Create a new command registration.
+    #[must_use]
+    pub const fn new(constructor: fn() -> Box<dyn Command + Send + Sync>) -> Self {
+        Self { constructor }
+    }
+}
+
+inventory::collect!(CommandRegistration);
+
+/// Returns an instance of every registered command.
+///
+/// Commands are only registered if the crate they are declared in is linked into the final
+/// binary.
+pub fn commands() -> impl Iterator<Item = Box<dyn Command + Send + Sync>> {
+    inventory::iter::<CommandRegistration>
+        .into_iter()
+        .map(|registration| (registration.constructor)())
+}
+
+/// Returns the gateway intents needed by the plugins of every registered command.
+pub fn intents() -> Intents {
+    commands().fold(Intents::empty(), |intents, command| {
+        intents | command.plugin().intents()
+    })
+}
@@ -0,0 +1,243 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Interaction Responses
+//!
+//! Discord requires an interaction to be responded to within three seconds. The response context
+//! keeps track of whether the interaction has been responded to already, such that slow commands
+//! can be deferred and later responses are turned into edits of the original response or
+//! follow-up messages accordingly.
+
+use std::sync::Mutex;
+
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::channel::message::MessageFlags;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponseData;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponseType;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::InteractionMarker;
+use hartex_discord_core::discord::util::builder::InteractionResponseDataBuilder;
+use miette::IntoDiagnostic;
+use miette::miette;
+
+/// The state of the response to an interaction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResponseState {
+    /// The interaction has not been responded to.
+    Pending,
+    /// The initial response to the interaction is being sent.
+    Responding,
+    /// The interaction has been deferred, the original response is a loading state.
+    Deferred {
+        /// Whether the loading state is only visible to the invoking user.
+        ephemeral: bool,
+    },
+    /// The interaction has been responded to.
+    Responded,
+}
+
+/// The response context of an interaction.
+pub struct ResponseContext<'a> {
+    interaction_client: &'a InteractionClient<'a>,
+    interaction_id: Id<InteractionMarker>,
+    token: String,
+    state: Mutex<ResponseState>,
+}
+
+#[allow(clippy::missing_errors_doc)]
+#[allow(clippy::missing_panics_doc)]
+impl<'a> ResponseContext<'a> {
+    /// Create a new response context for an interaction.
+    pub fn new(interaction_client: &'a InteractionClient<'a>, interaction: &Interaction) -> Self {
+        Self {
+            interaction_client,
+            interaction_id: interaction.id,
+            token: interaction.token.clone(),
+            state: Mutex::new(ResponseState::Pending),
+        }
+    }
+
+    /// The interaction client used for responding to the interaction.
+    pub fn interaction_client(&self) -> &InteractionClient<'a> {
+        self.interaction_client
+    }
+
+    /// The current state of the response.
+    pub fn state(&self) -> ResponseState {
+        *self.state.lock().unwrap()
+    }
+
+    /// Defers the response to the interaction, showing a loading state to the user.
+    ///
+    /// This does nothing if the interaction is being or has already been deferred or responded
+    /// to.
+    pub async fn defer(&self, ephemeral: bool) -> miette::Result<()> {
+        if self.begin_initial_response() != ResponseState::Pending {
+            return Ok(());
+        }
+
+        let data = ephemeral.then(|| {
+            InteractionResponseDataBuilder::new()
+                .flags(MessageFlags::EPHEMERAL)
+                .build()
+        });
+
+        let result = self
+            .interaction_client
+            .create_response(
+                self.interaction_id,
+                &self.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::DeferredChannelMessageWithSource,
+                    data,
+                },
+            )
+            .await
+            .into_diagnostic();
+
+        *self.state.lock().unwrap() = if result.is_ok() {
+            ResponseState::Deferred { ephemeral }
+        } else {
+            ResponseState::Pending
+        };
+
+        result.map(|_| ())
+    }
+
+    /// Responds to the interaction.
+    ///
+    /// If the interaction has been deferred, the original response is edited instead. If the
+    /// interaction is being or has already been responded to, a follow-up message is sent instead.
+    /// Modal and autocomplete responses can only be sent as the initial response, and are
+    /// rejected with an error otherwise.
+    pub async fn respond(&self, response: &InteractionResponse) -> miette::Result<()> {
+        let state = self.begin_initial_response();
+        if state != ResponseState::Pending
+            && matches!(
+                response.kind,
+                InteractionResponseType::ApplicationCommandAutocompleteResult
+                    | InteractionResponseType::Modal
+            )
+        {
+            return Err(miette!(
+                "{:?} responses cannot be sent after the interaction has been deferred or responded to",
+                response.kind
+            ));
+        }
+
+        match (state, &response.data) {
+            (ResponseState::Pending, _) => {
+                let result = self
+                    .interaction_client
+                    .create_response(self.interaction_id, &self.token, response)
+                    .await
+                    .into_diagnostic();
+
+                if let Err(error) = result {
+                    *self.state.lock().unwrap() = ResponseState::Pending;
+                    return Err(error);
+                }
+            }
+            (ResponseState::Deferred { ephemeral }, Some(data))
+                if !ephemeral
+                    && data
+                        .flags
+                        .is_some_and(|flags| flags.contains(MessageFlags::EPHEMERAL)) =>
+            {
+                // the flags of the original response cannot be changed by editing it, so the
+                // loading state is replaced with an ephemeral follow-up message instead
+                self.interaction_client
+                    .delete_response(&self.token)
+                    .await
+                    .into_diagnostic()?;
+                self.followup(data).await?;
+            }
+            (ResponseState::Deferred { .. }, Some(data)) => self.update(data).await?,
+            (ResponseState::Responding | ResponseState::Responded, Some(data)) => {
+                self.followup(data).await?;
+            }
+            (_, None) => (),
+        }
+
+        // the state is left to whoever is sending the initial response
+        if state != ResponseState::Responding {
+            *self.state.lock().unwrap() = ResponseState::Responded;
+        }
+
+        Ok(())
+    }
+
+    /// Edits the original response to the interaction.
+    pub async fn update(&self, data: &InteractionResponseData) -> miette::Result<()> {
+        self.interaction_client
+            .update_response(&self.token)
+            .content(data.content.as_deref())
+            .embeds(data.embeds.as_deref())
+            .components(data.components.as_deref())
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+
+    /// Sends a follow-up message to the interaction.
+    pub async fn followup(&self, data: &InteractionResponseData) -> miette::Result<()> {
+        let mut followup = self.interaction_client.create_followup(&self.token);
+
+        if let Some(content) = &data.content {
+            followup = followup.content(content);
+        }
+
+        if let Some(embeds) = &data.embeds {
+            followup = followup.embeds(embeds);
+        }
+
+        if let Some(components) = &data.components {
+            followup = followup.components(components);
+        }
+
+        if let Some(flags) = data.flags {
+            followup = followup.flags(flags);
+        }
+
+        followup.await.into_diagnostic()?;
+
+        Ok(())
+    }
+
+    /// Marks the initial response as being sent if the interaction has not been responded to
+    /// yet, such that concurrent responses do not send a second initial response.
+    ///
+    /// Returns the state of the response before it was marked, where only
+    /// [`ResponseState::Pending`] means that the caller is to send the initial response.
+    fn begin_initial_response(&self) -> ResponseState {
+        let mut state = self.state.lock().unwrap();
+        let previous = *state;
+        if previous == ResponseState::Pending {
+            *state = ResponseState::Responding;
+        }
+
+        previous
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Context Menu Command Targets
+//!
+//! User and message commands are invoked from the context menu of a user or a message, which is
+//! the target of the command and is resolved by Discord alongside the interaction.
+
+use hartex_discord_core::discord::model::application::interaction::InteractionMember;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandData;
+use hartex_discord_core::discord::model::channel::Message;
+use hartex_discord_core::discord::model::user::User;
+
+/// The target of a user command.
+#[derive(Clone, Debug)]
+pub struct UserTarget {
+    /// The targeted user.
+    pub user: User,
+    /// The targeted user as a member of the guild the command is invoked in, if any.
+    pub member: Option<InteractionMember>,
+}
+
+impl UserTarget {
+    /// Obtains the target of a user command from the command data, if any.
+    #[must_use]
+    pub fn from_command_data(data: &CommandData) -> Option<Self> {
+        let user_id = data.target_id?.cast();
+        let resolved = data.resolved.as_ref()?;
+
+        Some(Self {
+            user: resolved.users.get(&user_id)?.clone(),
+            member: resolved.members.get(&user_id).cloned(),
+        })
+    }
+}
+
+/// The target of a message command.
+#[derive(Clone, Debug)]
+pub struct MessageTarget {
+    /// The targeted message.
+    pub message: Message,
+}
+
+impl MessageTarget {
+    /// Obtains the target of a message command from the command data, if any.
+    #[must_use]
+    pub fn from_command_data(data: &CommandData) -> Option<Self> {
+        let message_id = data.target_id?.cast();
+        let resolved = data.resolved.as_ref()?;
+
+        Some(Self {
+            message: resolved.messages.get(&message_id)?.clone(),
+        })
+    }
+}
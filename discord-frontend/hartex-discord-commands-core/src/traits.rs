@@ -22,13 +22,20 @@
 
 use async_trait::async_trait;
 use hartex_discord_configuration_provider::ConfigurationProvider;
-use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::command::CommandType;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::gateway::Intents;
 use hartex_discord_core::discord::model::guild::Permissions;
 use hartex_discord_core::discord::model::id::Id;
 use hartex_discord_core::discord::model::id::marker::GuildMarker;
 use hartex_localization_core::Localizer;
 
+use crate::components::CustomId;
+use crate::cooldowns::Cooldown;
+use crate::response::ResponseContext;
+use crate::targets::MessageTarget;
+use crate::targets::UserTarget;
+
 /// The command metadata trait, specifying the various information about a command.
 pub trait CommandMetadata {
     /// The minimum permission level required for this command to be run.
@@ -36,6 +43,18 @@ pub trait CommandMetadata {
         Permissions::empty()
     }
 
+    /// The cooldown of the command, if any.
+    ///
+    /// Guilds may override this in their configuration.
+    fn cooldown(&self) -> Option<Cooldown> {
+        None
+    }
+
+    /// The type of the command.
+    fn kind(&self) -> CommandType {
+        CommandType::ChatInput
+    }
+
     /// The name of the command.
     fn name(&self) -> String;
 
@@ -47,13 +66,84 @@ pub trait CommandMetadata {
 /// is handled.
 #[async_trait]
 pub trait Command: CommandMetadata {
-    /// Executes the command.
+    /// Executes the command when invoked as a chat input command.
+    ///
+    /// Context menu commands need not implement this.
     async fn execute(
         &self,
-        interaction: Interaction,
-        interaction_client: &InteractionClient<'_>,
-        localizer: Localizer<'_>,
-    ) -> miette::Result<()>;
+        _: Interaction,
+        _: &ResponseContext<'_>,
+        _: Localizer<'_>,
+    ) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Executes the command when invoked from the context menu of a user.
+    ///
+    /// This is only invoked for user commands, so other commands need not implement it.
+    async fn execute_user(
+        &self,
+        _: Interaction,
+        _: UserTarget,
+        _: &ResponseContext<'_>,
+        _: Localizer<'_>,
+    ) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Executes the command when invoked from the context menu of a message.
+    ///
+    /// This is only invoked for message commands, so other commands need not implement it.
+    async fn execute_message(
+        &self,
+        _: Interaction,
+        _: MessageTarget,
+        _: &ResponseContext<'_>,
+        _: Localizer<'_>,
+    ) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Responds to an autocomplete interaction for one of the options of the command.
+    ///
+    /// This is only invoked for options that have autocomplete enabled in the command
+    /// specification, so commands without such options need not implement it.
+    async fn autocomplete(
+        &self,
+        _: Interaction,
+        _: &ResponseContext<'_>,
+        _: Localizer<'_>,
+    ) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Handles a message component interaction whose custom ID is namespaced under this command.
+    ///
+    /// This is only invoked for components created by the command itself, so commands that do not
+    /// send any components need not implement it.
+    async fn component(
+        &self,
+        _: Interaction,
+        _: &ResponseContext<'_>,
+        _: CustomId,
+        _: Localizer<'_>,
+    ) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Handles a modal submit interaction whose custom ID is namespaced under this command.
+    ///
+    /// This is only invoked for modals opened by the command itself, so commands that do not open
+    /// any modals need not implement it.
+    async fn modal_submit(
+        &self,
+        _: Interaction,
+        _: &ResponseContext<'_>,
+        _: CustomId,
+        _: Localizer<'_>,
+    ) -> miette::Result<()> {
+        Ok(())
+    }
 }
 
 /// The plugin metadata data specifying information about a plugin.
@@ -70,4 +160,10 @@ pub trait Plugin: PluginMetadata {
     async fn enabled(&self, guild_id: Id<GuildMarker>) -> miette::Result<bool> {
         ConfigurationProvider::plugin_enabled(guild_id, self.name()).await
     }
+
+    /// The gateway intents the plugin needs, such as for keeping the entities its commands read
+    /// from the entity cache up to date.
+    fn intents(&self) -> Intents {
+        Intents::empty()
+    }
 }
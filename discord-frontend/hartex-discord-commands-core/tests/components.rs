@@ -0,0 +1,70 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_commands_core::components::CustomId;
+use hartex_discord_commands_core::components::CustomIdErrorKind;
+
+#[test]
+fn custom_id_without_state_round_trips() {
+    let custom_id = CustomId::new("utilities", "report", "submit");
+
+    assert_eq!(custom_id.to_string(), "utilities:report:submit");
+    assert_eq!(
+        custom_id.to_string().parse::<CustomId>().unwrap(),
+        custom_id
+    );
+}
+
+#[test]
+fn custom_id_with_state_round_trips() {
+    let custom_id = CustomId::new("utilities", "report", "submit").state("1234:5678");
+
+    assert_eq!(custom_id.to_string(), "utilities:report:submit:1234:5678");
+    assert_eq!(
+        custom_id.to_string().parse::<CustomId>().unwrap(),
+        custom_id
+    );
+}
+
+#[test]
+fn custom_id_with_empty_state_round_trips() {
+    let custom_id = CustomId::new("utilities", "report", "submit").state("");
+
+    assert_eq!(
+        custom_id.to_string().parse::<CustomId>().unwrap(),
+        custom_id
+    );
+}
+
+#[test]
+fn custom_id_with_missing_segment_is_rejected() {
+    let error = "utilities:report".parse::<CustomId>().unwrap_err();
+
+    assert!(matches!(error.kind, CustomIdErrorKind::MissingSegment));
+}
+
+#[test]
+fn custom_id_with_empty_segment_is_rejected() {
+    let error = "utilities::submit".parse::<CustomId>().unwrap_err();
+
+    assert!(matches!(error.kind, CustomIdErrorKind::EmptySegment));
+}
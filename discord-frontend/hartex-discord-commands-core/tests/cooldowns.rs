@@ -0,0 +1,47 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use hartex_discord_commands_core::cooldowns::Cooldown;
+use hartex_discord_commands_core::cooldowns::CooldownBucket;
+use hartex_discord_commands_core::cooldowns::CooldownTracker;
+
+#[test]
+fn active_cooldown_rejects_trigger() {
+    let tracker = CooldownTracker::new();
+    let cooldown = Cooldown::new(CooldownBucket::User, Duration::from_secs(60));
+
+    assert!(tracker.trigger("ping", cooldown, 1).is_ok());
+    assert!(tracker.trigger("ping", cooldown, 1).is_err());
+    assert!(tracker.trigger("ping", cooldown, 2).is_ok());
+    assert!(tracker.trigger("uptime", cooldown, 1).is_ok());
+}
+
+#[test]
+fn expired_cooldown_allows_trigger() {
+    let tracker = CooldownTracker::new();
+    let cooldown = Cooldown::new(CooldownBucket::Guild, Duration::ZERO);
+
+    assert!(tracker.trigger("ping", cooldown, 1).is_ok());
+    assert!(tracker.trigger("ping", cooldown, 1).is_ok());
+}
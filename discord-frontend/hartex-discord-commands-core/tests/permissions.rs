@@ -0,0 +1,157 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_commands_core::permissions::CommandPermissions;
+use hartex_discord_commands_core::permissions::PermissionCheck;
+use hartex_discord_commands_core::permissions::PermissionRule;
+use hartex_discord_commands_core::permissions::PermissionSubject;
+use hartex_discord_commands_core::permissions::PermissionTarget;
+use hartex_discord_core::discord::model::guild::Permissions;
+use hartex_discord_core::discord::model::id::Id;
+
+const CHANNEL: PermissionTarget = PermissionTarget::Channel(Id::new(1));
+const ROLE: PermissionTarget = PermissionTarget::Role(Id::new(2));
+const USER: PermissionTarget = PermissionTarget::User(Id::new(3));
+const OTHER_ROLE: PermissionTarget = PermissionTarget::Role(Id::new(4));
+
+fn subject(permissions: Permissions) -> PermissionSubject {
+    PermissionSubject {
+        user_id: Id::new(3),
+        roles: vec![Id::new(2), Id::new(4)],
+        channel_id: Some(Id::new(1)),
+        permissions,
+    }
+}
+
+fn permissions(
+    replace_required_permissions: bool,
+    rules: &[(PermissionTarget, bool)],
+) -> CommandPermissions {
+    CommandPermissions {
+        replace_required_permissions,
+        rules: rules
+            .iter()
+            .map(|(target, allow)| PermissionRule {
+                target: *target,
+                allow: *allow,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn required_permissions_apply_without_rules() {
+    let permissions = permissions(false, &[]);
+
+    assert_eq!(
+        permissions.evaluate(
+            &subject(Permissions::MANAGE_GUILD),
+            Permissions::MANAGE_GUILD
+        ),
+        PermissionCheck::AllowedByPermissions
+    );
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::empty()), Permissions::MANAGE_GUILD),
+        PermissionCheck::DeniedByPermissions(Permissions::MANAGE_GUILD)
+    );
+}
+
+#[test]
+fn channel_deny_takes_precedence_over_user_allow() {
+    let permissions = permissions(true, &[(USER, true), (CHANNEL, false)]);
+
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::all()), Permissions::empty()),
+        PermissionCheck::DeniedByRule(CHANNEL)
+    );
+}
+
+#[test]
+fn user_rule_takes_precedence_over_role_rules() {
+    let permissions = permissions(true, &[(ROLE, true), (USER, false)]);
+
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::all()), Permissions::empty()),
+        PermissionCheck::DeniedByRule(USER)
+    );
+
+    let permissions = self::permissions(true, &[(ROLE, false), (USER, true)]);
+
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::empty()), Permissions::MANAGE_GUILD),
+        PermissionCheck::AllowedByRule(USER)
+    );
+}
+
+#[test]
+fn role_allow_takes_precedence_over_role_deny() {
+    let permissions = permissions(true, &[(OTHER_ROLE, false), (ROLE, true)]);
+
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::empty()), Permissions::MANAGE_GUILD),
+        PermissionCheck::AllowedByRule(ROLE)
+    );
+}
+
+#[test]
+fn role_allow_only_replaces_required_permissions_when_configured() {
+    let permissions = permissions(false, &[(ROLE, true)]);
+
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::empty()), Permissions::MANAGE_GUILD),
+        PermissionCheck::DeniedByPermissions(Permissions::MANAGE_GUILD)
+    );
+}
+
+#[test]
+fn channel_allow_replaces_required_permissions_when_configured() {
+    let permissions = permissions(true, &[(CHANNEL, true)]);
+
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::empty()), Permissions::MANAGE_GUILD),
+        PermissionCheck::AllowedByRule(CHANNEL)
+    );
+
+    let permissions = self::permissions(false, &[(CHANNEL, true)]);
+
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::empty()), Permissions::MANAGE_GUILD),
+        PermissionCheck::DeniedByPermissions(Permissions::MANAGE_GUILD)
+    );
+    assert_eq!(
+        permissions.evaluate(
+            &subject(Permissions::MANAGE_GUILD),
+            Permissions::MANAGE_GUILD
+        ),
+        PermissionCheck::AllowedByRule(CHANNEL)
+    );
+}
+
+#[test]
+fn role_rules_take_precedence_over_channel_allow() {
+    let permissions = permissions(true, &[(CHANNEL, true), (ROLE, false)]);
+
+    assert_eq!(
+        permissions.evaluate(&subject(Permissions::all()), Permissions::empty()),
+        PermissionCheck::DeniedByRule(ROLE)
+    );
+}
@@ -22,6 +22,7 @@
 
 use proc_macro2::TokenStream as TokenStream2;
 use syn::Expr;
+use syn::ExprLit;
 use syn::Ident;
 use syn::ItemStruct;
 use syn::Lit;
@@ -40,11 +41,7 @@ pub struct CommandMetadataMacroInput {
     pub(self) plugin_ident: Ident,
     pub(self) equal1: Token![=],
     pub(self) plugin_actual_ident: Ident,
-    pub(self) comma3: Option<Token![,]>,
-    pub(self) minimum_permission_level_ident: Option<Ident>,
-    pub(self) equal_2: Option<Token![=]>,
-    pub(self) minimum_permission_level: Option<Expr>,
-    pub(self) comma4: Option<Token![,]>,
+    pub(self) optional_parameters: Vec<(Ident, Expr)>,
 }
 
 impl Parse for CommandMetadataMacroInput {
@@ -57,21 +54,16 @@ impl Parse for CommandMetadataMacroInput {
             plugin_ident: input.parse()?,
             equal1: input.parse()?,
             plugin_actual_ident: input.parse()?,
-            comma3: None,
-            minimum_permission_level_ident: None,
-            equal_2: None,
-            minimum_permission_level: None,
-            comma4: None,
+            optional_parameters: Vec::new(),
         };
 
-        let Some(comma_3) = input.parse().ok() else {
-            return Ok(result);
-        };
+        while input.parse::<Token![,]>().is_ok() && !input.is_empty() {
+            let ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let expr = input.parse()?;
 
-        result.comma3.replace(comma_3);
-        result.minimum_permission_level_ident = input.parse().ok();
-        result.equal_2 = input.parse().ok();
-        result.comma4 = input.parse().ok();
+            result.optional_parameters.push((ident, expr));
+        }
 
         Ok(result)
     }
@@ -134,38 +126,98 @@ pub fn implement_metadata(
     };
     functions.extend(expanded);
 
-    // minimum_permission_level = ?
-    if let Some(minimum_permission_level_ident) = parameters.minimum_permission_level_ident.clone()
-        && minimum_permission_level_ident == "required_permissions"
-    {
-        let Some(_) = parameters.equal_2 else {
-            parameters
-                .equal_2
-                .span()
-                .unwrap()
-                .error("expected equal")
-                .emit();
-
-            return None;
-        };
-
-        let Some(Expr::Binary(expr)) = parameters.minimum_permission_level.clone() else {
-            parameters
-                .minimum_permission_level
-                .span()
-                .unwrap()
-                .error("expected expression")
-                .emit();
-
-            return None;
-        };
-
-        let expanded = quote::quote! {
-            fn required_permissions(&self) -> Permissions {
-                #expr
+    for (ident, expr) in &parameters.optional_parameters {
+        match ident.to_string().as_str() {
+            // required_permissions = ?
+            "required_permissions" => {
+                let expanded = quote::quote! {
+                    fn required_permissions(&self) -> Permissions {
+                        #expr
+                    }
+                };
+                functions.extend(expanded);
             }
-        };
-        functions.extend(expanded);
+            // cooldown = ?
+            "cooldown" => {
+                let Expr::Call(call) = expr else {
+                    expr.span()
+                        .unwrap()
+                        .error("expected `user(seconds)`, `channel(seconds)` or `guild(seconds)`")
+                        .emit();
+
+                    return None;
+                };
+
+                let bucket = match &*call.func {
+                    Expr::Path(path) if path.path.is_ident("user") => quote::quote!(User),
+                    Expr::Path(path) if path.path.is_ident("channel") => quote::quote!(Channel),
+                    Expr::Path(path) if path.path.is_ident("guild") => quote::quote!(Guild),
+                    _ => {
+                        call.func
+                            .span()
+                            .unwrap()
+                            .error("expected `user`, `channel` or `guild`")
+                            .emit();
+
+                        return None;
+                    }
+                };
+
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Int(seconds),
+                    ..
+                })) = call.args.first()
+                else {
+                    call.args.span().unwrap().error("expected integer").emit();
+
+                    return None;
+                };
+
+                let expanded = quote::quote! {
+                    fn cooldown(&self) -> Option<_commands_core::cooldowns::Cooldown> {
+                        Some(_commands_core::cooldowns::Cooldown::new(
+                            _commands_core::cooldowns::CooldownBucket::#bucket,
+                            ::std::time::Duration::from_secs(#seconds),
+                        ))
+                    }
+                };
+                functions.extend(expanded);
+            }
+            // kind = ?
+            "kind" => {
+                let kind = match expr {
+                    Expr::Path(path) if path.path.is_ident("chat_input") => {
+                        quote::quote!(ChatInput)
+                    }
+                    Expr::Path(path) if path.path.is_ident("user") => quote::quote!(User),
+                    Expr::Path(path) if path.path.is_ident("message") => quote::quote!(Message),
+                    _ => {
+                        expr.span()
+                            .unwrap()
+                            .error("expected `chat_input`, `user` or `message`")
+                            .emit();
+
+                        return None;
+                    }
+                };
+
+                let expanded = quote::quote! {
+                    fn kind(&self) -> hartex_discord_core::discord::model::application::command::CommandType {
+                        hartex_discord_core::discord::model::application::command::CommandType::#kind
+                    }
+                };
+                functions.extend(expanded);
+            }
+            _ => {
+                ident
+                    .span()
+                    .unwrap()
+                    .error("expected `required_permissions`, `cooldown` or `kind`")
+                    .emit();
+
+                return None;
+            }
+        }
     }
 
     let core_use = quote::quote! {
@@ -183,6 +235,12 @@ pub fn implement_metadata(
         impl _commands_core::traits::CommandMetadata for #ident {
             #functions
         }
+
+        _commands_core::registry::inventory::submit! {
+            _commands_core::registry::CommandRegistration::new(
+                || -> Box<dyn _commands_core::traits::Command + Send + Sync> { Box::new(#ident) }
+            )
+        }
     };
 
     Some(quote::quote! {
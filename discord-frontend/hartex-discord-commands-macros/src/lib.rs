@@ -39,7 +39,8 @@ use syn::parse_macro_input;
 mod commandmetadata;
 mod pluginmetadata;
 
-/// Macro to implement the `CommandMetadata` trait.
+/// Macro to implement the `CommandMetadata` trait and register the command in the command
+/// registry.
 #[proc_macro_attribute]
 pub fn command(tokens: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(tokens as commandmetadata::CommandMetadataMacroInput);
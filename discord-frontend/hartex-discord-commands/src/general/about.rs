@@ -27,8 +27,8 @@
 
 use async_trait::async_trait;
 use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::response::ResponseContext;
 use hartex_discord_commands_core::traits::Command;
-use hartex_discord_core::discord::http::client::InteractionClient;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
 use hartex_discord_core::discord::util::builder::embed::EmbedAuthorBuilder;
 use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
@@ -49,8 +49,8 @@ pub struct About;
 impl Command for About {
     async fn execute(
         &self,
-        interaction: Interaction,
-        interaction_client: &InteractionClient<'_>,
+        _: Interaction,
+        context: &ResponseContext<'_>,
         localizer: Localizer<'_>,
     ) -> miette::Result<()> {
         let about_embed_title = localizer.general_plugin_about_embed_title()?;
@@ -73,14 +73,7 @@ impl Command for About {
             .into_diagnostic()?
             .build();
 
-        interaction_client
-            .create_response(
-                interaction.id,
-                &interaction.token,
-                &embed_response(vec![embed]),
-            )
-            .await
-            .into_diagnostic()?;
+        context.respond(&embed_response(vec![embed])).await?;
 
         Ok(())
     }
@@ -0,0 +1,119 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Latency Command
+//!
+//! This command returns the most recent heartbeat latency of each shard, as published by the
+//! leader.
+
+use async_trait::async_trait;
+use chrono::TimeDelta;
+use chrono::Utc;
+use hartex_database_queries::queries::discord_frontend::shard_latency_select_updated_after::ShardLatencySelectUpdatedAfter;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_utils::interaction::embed_response;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+use crate::general::General;
+
+/// Latencies that have not been updated for this many seconds are considered stale, as the shard
+/// has likely disconnected.
+const STALE_AFTER_SECS: i64 = 120;
+
+/// Embeds allow at most 25 fields, one of which is taken by the average.
+const MAX_SHARD_FIELDS: usize = 24;
+
+/// The `latency` command declaration.
+#[command(name = "latency", plugin = General)]
+pub struct Latency;
+
+#[async_trait]
+impl Command for Latency {
+    async fn execute(
+        &self,
+        _: Interaction,
+        context: &ResponseContext<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let updated_after = Utc::now() - TimeDelta::seconds(STALE_AFTER_SECS);
+        let latencies = ShardLatencySelectUpdatedAfter::bind(updated_after)
+            .executor()
+            .await
+            .into_diagnostic()?
+            .many()
+            .await
+            .into_diagnostic()?;
+
+        if latencies.is_empty() {
+            let message = localizer.general_plugin_latency_error_unavailable()?;
+            context.respond(&ephemeral_error_response(message)).await?;
+
+            return Ok(());
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        #[allow(clippy::cast_possible_wrap)]
+        let average = latencies
+            .iter()
+            .map(|latency| latency.latency())
+            .sum::<i64>()
+            / latencies.len() as i64;
+
+        let latency_embed_average_field_name =
+            localizer.general_plugin_latency_embed_average_field_name()?;
+        let latency_embed_title = localizer.general_plugin_latency_embed_title()?;
+
+        let mut builder = EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .field(EmbedFieldBuilder::new(
+                latency_embed_average_field_name,
+                format!("{average}ms").discord_inline_code(),
+            ))
+            .title(latency_embed_title);
+
+        for latency in latencies.iter().take(MAX_SHARD_FIELDS) {
+            let latency_embed_shard_field_name =
+                localizer.general_plugin_latency_embed_shard_field_name(latency.shard_id())?;
+
+            builder = builder.field(
+                EmbedFieldBuilder::new(
+                    latency_embed_shard_field_name,
+                    format!("{}ms", latency.latency()).discord_inline_code(),
+                )
+                .inline(),
+            );
+        }
+
+        let embed = builder.validate().into_diagnostic()?.build();
+        context.respond(&embed_response(vec![embed])).await?;
+
+        Ok(())
+    }
+}
@@ -25,6 +25,8 @@
 //! Command list:
 //! - about
 //! - contributors
+//! - latency
+//! - uptime
 
 use async_trait::async_trait;
 use hartex_discord_commands_core::plugin;
@@ -34,6 +36,8 @@ use hartex_discord_core::discord::model::id::marker::GuildMarker;
 
 pub mod about;
 pub mod contributors;
+pub mod latency;
+pub mod uptime;
 
 /// The general plugin.
 #[plugin(name = "general")]
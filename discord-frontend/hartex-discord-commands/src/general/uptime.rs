@@ -0,0 +1,129 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Uptime Command
+//!
+//! This command returns when the bot was last started, as recorded by the API backend.
+
+use std::env;
+
+use async_trait::async_trait;
+use hartex_backend_models::Response;
+use hartex_backend_models::uptime::UptimeQuery;
+use hartex_backend_models::uptime::UptimeResponse;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_core::tokio::net::TcpStream;
+use hartex_discord_core::tokio::task::spawn;
+use hartex_discord_utils::interaction::embed_response;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use hartex_log::log;
+use http_body_util::BodyExt;
+use http_body_util::Empty;
+use hyper::Method;
+use hyper::Request;
+use hyper::body::Buf;
+use hyper::body::Bytes;
+use hyper::client::conn::http1::handshake;
+use hyper::header::ACCEPT;
+use hyper_util::rt::TokioIo;
+use miette::IntoDiagnostic;
+use miette::Report;
+
+use crate::general::General;
+
+/// The `uptime` command declaration.
+#[command(name = "uptime", plugin = General)]
+pub struct Uptime;
+
+#[async_trait]
+impl Command for Uptime {
+    async fn execute(
+        &self,
+        _: Interaction,
+        context: &ResponseContext<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let api_domain = env::var("API_DOMAIN").into_diagnostic()?;
+        let query = UptimeQuery::new("HarTex Nightly");
+        let uri = format!(
+            "http://{}/api/v1/stats/uptime?{}",
+            api_domain.clone(),
+            serde_urlencoded::to_string(&query).into_diagnostic()?
+        );
+
+        let stream = TcpStream::connect(api_domain).await.into_diagnostic()?;
+        let (mut sender, connection) = handshake(TokioIo::new(stream)).await.into_diagnostic()?;
+
+        spawn(async move {
+            if let Err(err) = connection.await {
+                log::error!("TCP connection failed: {:?}", err);
+            }
+        });
+
+        log::debug!("sending a request to {}", &uri);
+
+        let request = Request::builder()
+            .uri(uri)
+            .method(Method::GET)
+            .header(ACCEPT, "application/json")
+            .body(Empty::<Bytes>::new())
+            .into_diagnostic()?;
+
+        let result = sender.send_request(request).await.into_diagnostic()?;
+        log::debug!("deserializing result");
+        let body = result.collect().await.into_diagnostic()?.aggregate();
+        let response: Response<UptimeResponse, String> =
+            serde_json::from_reader(body.reader()).into_diagnostic()?;
+
+        let data = response.data();
+        let timestamp = data
+            .left()
+            .flatten()
+            .ok_or(Report::msg("failed to obtain uptime data"))?
+            .start_timestamp();
+
+        let uptime_embed_started_field_name =
+            localizer.general_plugin_uptime_embed_started_field_name()?;
+        let uptime_embed_title = localizer.general_plugin_uptime_embed_title()?;
+
+        let embed = EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .field(EmbedFieldBuilder::new(
+                uptime_embed_started_field_name,
+                timestamp.to_string().discord_relative_timestamp(),
+            ))
+            .title(uptime_embed_title)
+            .validate()
+            .into_diagnostic()?
+            .build();
+
+        context.respond(&embed_response(vec![embed])).await?;
+
+        Ok(())
+    }
+}
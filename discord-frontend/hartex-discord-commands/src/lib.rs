@@ -34,4 +34,5 @@
 #![feature(let_chains)]
 
 pub mod general;
+pub mod support;
 pub mod utilities;
@@ -0,0 +1,171 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Error Lookup Subcommand
+//!
+//! This command retrieves the full context of an error from the error code quoted by a user.
+
+use hartex_database_queries::queries::discord_frontend::error_report_select_by_hash::ErrorReportSelectByHash;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_utils::commands::CommandDataOptionExt;
+use hartex_discord_utils::commands::CommandDataOptionsExt;
+use hartex_discord_utils::interaction::ephemeral_embed_response;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+/// The maximum number of characters of the report to display, leaving room for the code block
+/// within the embed description limit.
+const MAX_REPORT_LENGTH: usize = 4000;
+
+/// The maximum number of characters of the interaction options to display, leaving room for the
+/// code block within the embed field value limit.
+const MAX_OPTIONS_LENGTH: usize = 1000;
+
+/// Executes the `error lookup` command.
+pub async fn execute(
+    _: Interaction,
+    context: &ResponseContext<'_>,
+    option: CommandDataOption,
+    localizer: Localizer<'_>,
+) -> miette::Result<()> {
+    let options = option.assume_subcommand();
+    let code = options.string_value_of("code").trim().to_string();
+
+    let reports = ErrorReportSelectByHash::bind(code.clone())
+        .executor()
+        .await
+        .into_diagnostic()?
+        .many()
+        .await
+        .into_diagnostic()?;
+    let Some(report) = reports.into_iter().next() else {
+        context
+            .respond(&ephemeral_error_response(
+                localizer.support_plugin_errorlookup_error_not_found(code)?,
+            ))
+            .await?;
+
+        return Ok(());
+    };
+
+    let unknown = localizer.support_plugin_errorlookup_embed_unknown()?;
+    let errorlookup_embed_command_field_name =
+        localizer.support_plugin_errorlookup_embed_command_field_name()?;
+    let errorlookup_embed_guild_field_name =
+        localizer.support_plugin_errorlookup_embed_guild_field_name()?;
+    let errorlookup_embed_kind_field_name =
+        localizer.support_plugin_errorlookup_embed_kind_field_name()?;
+    let errorlookup_embed_options_field_name =
+        localizer.support_plugin_errorlookup_embed_options_field_name()?;
+    let errorlookup_embed_timestamp_field_name =
+        localizer.support_plugin_errorlookup_embed_timestamp_field_name()?;
+    let errorlookup_embed_title = localizer.support_plugin_errorlookup_embed_title(code)?;
+    let errorlookup_embed_user_field_name =
+        localizer.support_plugin_errorlookup_embed_user_field_name()?;
+
+    let embed = EmbedBuilder::new()
+        .color(if report.kind() == "panic" {
+            0xFF_33_33
+        } else {
+            0xFF_99_33
+        })
+        .description(truncate(report.report(), MAX_REPORT_LENGTH).discord_codeblock())
+        .field(
+            EmbedFieldBuilder::new(
+                errorlookup_embed_kind_field_name,
+                report.kind().to_string().discord_inline_code(),
+            )
+            .inline(),
+        )
+        .field(
+            EmbedFieldBuilder::new(
+                errorlookup_embed_command_field_name,
+                report.command().map_or(unknown.clone(), |command| {
+                    command.to_string().discord_inline_code()
+                }),
+            )
+            .inline(),
+        )
+        .field(
+            EmbedFieldBuilder::new(
+                errorlookup_embed_timestamp_field_name,
+                report
+                    .timestamp()
+                    .timestamp()
+                    .to_string()
+                    .discord_relative_timestamp(),
+            )
+            .inline(),
+        )
+        .field(
+            EmbedFieldBuilder::new(
+                errorlookup_embed_guild_field_name,
+                report.guild_id().map_or(unknown.clone(), |guild_id| {
+                    guild_id.to_string().discord_inline_code()
+                }),
+            )
+            .inline(),
+        )
+        .field(
+            EmbedFieldBuilder::new(
+                errorlookup_embed_user_field_name,
+                report
+                    .user_id()
+                    .map_or(unknown.clone(), |user_id| format!("<@{user_id}>")),
+            )
+            .inline(),
+        )
+        .field(EmbedFieldBuilder::new(
+            errorlookup_embed_options_field_name,
+            report.options().map_or(unknown, |options| {
+                truncate(options, MAX_OPTIONS_LENGTH).discord_codeblock()
+            }),
+        ))
+        .title(errorlookup_embed_title)
+        .validate()
+        .into_diagnostic()?
+        .build();
+
+    context
+        .respond(&ephemeral_embed_response(vec![embed]))
+        .await?;
+
+    Ok(())
+}
+
+/// Truncates a string to the given number of characters, marking it as truncated if necessary.
+fn truncate(string: &str, length: usize) -> String {
+    if string.chars().count() <= length {
+        return string.to_string();
+    }
+
+    let mut truncated = string.chars().take(length - 3).collect::<String>();
+    truncated.push_str("...");
+
+    truncated
+}
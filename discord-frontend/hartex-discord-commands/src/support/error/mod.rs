@@ -0,0 +1,92 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Error Command
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_localization_core::Localizer;
+
+use crate::support::Support;
+
+mod error_lookup;
+
+/// The `error` command declaration.
+///
+/// This is not named `Error` as to not clash with the standard error trait.
+#[command(name = "error", plugin = Support)]
+pub struct ErrorCommand;
+
+#[async_trait]
+impl Command for ErrorCommand {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        context: &ResponseContext<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        if !super::is_support_guild(interaction.guild_id) {
+            context
+                .respond(&ephemeral_error_response(
+                    localizer.support_plugin_support_error_not_in_support_guild()?,
+                ))
+                .await?;
+
+            return Ok(());
+        }
+
+        if !super::is_owner(interaction.author_id()).await? {
+            context
+                .respond(&ephemeral_error_response(
+                    localizer.support_plugin_support_error_not_owner()?,
+                ))
+                .await?;
+
+            return Ok(());
+        }
+
+        let Some(InteractionData::ApplicationCommand(command)) = interaction.clone().data else {
+            unreachable!()
+        };
+
+        let Some(subcommand) = command
+            .options
+            .iter()
+            .find(|option| matches!(option.value, CommandOptionValue::SubCommand(_)))
+        else {
+            unreachable!()
+        };
+
+        match subcommand.name.as_str() {
+            "lookup" => {
+                error_lookup::execute(interaction, context, subcommand.clone(), localizer).await
+            }
+            _ => unreachable!(),
+        }
+    }
+}
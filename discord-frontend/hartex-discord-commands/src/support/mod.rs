@@ -0,0 +1,84 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Support Plugin
+//!
+//! Commands for the owners of the bot, some of which can additionally only be used in the support
+//! server.
+//!
+//! Command list:
+//! - error
+//! - presence
+
+use std::env;
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::plugin;
+use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_utils::CLIENT;
+use miette::IntoDiagnostic;
+
+pub mod error;
+pub mod presence;
+
+/// The support plugin.
+#[plugin(name = "support")]
+pub struct Support;
+
+#[async_trait]
+impl Plugin for Support {
+    async fn enabled(&self, _: Id<GuildMarker>) -> miette::Result<bool> {
+        Ok(true)
+    }
+}
+
+/// Whether the given guild is the support server.
+fn is_support_guild(guild_id: Option<Id<GuildMarker>>) -> bool {
+    let support_guild_id = env::var("SUPPORT_GUILD_ID")
+        .ok()
+        .and_then(|id| id.parse::<Id<GuildMarker>>().ok());
+
+    guild_id.is_some() && guild_id == support_guild_id
+}
+
+/// Whether the given user owns the bot, either directly or as a member of the owning team.
+async fn is_owner(user_id: Option<Id<UserMarker>>) -> miette::Result<bool> {
+    let Some(user_id) = user_id else {
+        return Ok(false);
+    };
+
+    let application = CLIENT
+        .current_user_application()
+        .await
+        .into_diagnostic()?
+        .model()
+        .await
+        .into_diagnostic()?;
+
+    Ok(application.owner.is_some_and(|owner| owner.id == user_id)
+        || application
+            .team
+            .is_some_and(|team| team.members.iter().any(|member| member.user.id == user_id)))
+}
@@ -0,0 +1,128 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Presence Command
+//!
+//! This command changes the presence of the bot on every shard by sending it to the leader.
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_discord_internal_events::outbound::OutboundGatewayCommand;
+use hartex_discord_internal_events::outbound::OutboundGatewayCommandEnvelope;
+use hartex_discord_internal_events::outbound::OutboundTarget;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
+use hartex_discord_utils::commands::CommandDataOptionsExt;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::interaction::ephemeral_response;
+use hartex_discord_utils::presence::Presence;
+use hartex_discord_utils::presence::PresenceActivity;
+use hartex_discord_utils::presence::parse_activity_kind;
+use hartex_discord_utils::presence::parse_status;
+use hartex_localization_core::Localizer;
+use miette::miette;
+
+use crate::support::Support;
+
+/// The `presence` command declaration.
+///
+/// This is not named `Presence` as to not clash with the presence being set.
+#[command(name = "presence", plugin = Support)]
+pub struct PresenceCommand;
+
+#[async_trait]
+impl Command for PresenceCommand {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        context: &ResponseContext<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        if !super::is_owner(interaction.author_id()).await? {
+            context
+                .respond(&ephemeral_error_response(
+                    localizer.support_plugin_support_error_not_owner()?,
+                ))
+                .await?;
+
+            return Ok(());
+        }
+
+        let Some(InteractionData::ApplicationCommand(command)) = interaction.data else {
+            unreachable!()
+        };
+
+        let status = command.options.string_value_of("status");
+        let status =
+            parse_status(&status).ok_or_else(|| miette!("unknown presence status: {status}"))?;
+
+        let activity = match command
+            .options
+            .iter()
+            .find(|option| option.name == "activity")
+            .map(|option| option.value.clone())
+        {
+            Some(CommandOptionValue::String(name)) => {
+                let kind = command
+                    .options
+                    .iter()
+                    .find(|option| option.name == "activity-type")
+                    .and_then(|option| match &option.value {
+                        CommandOptionValue::String(kind) => Some(kind.clone()),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| String::from("playing"));
+                let kind = parse_activity_kind(&kind)
+                    .ok_or_else(|| miette!("unknown presence activity kind: {kind}"))?;
+
+                Some(PresenceActivity { kind, name })
+            }
+            _ => None,
+        };
+
+        let envelope = OutboundGatewayCommandEnvelope::new(
+            OutboundGatewayCommand::UpdatePresence(Presence { activity, status }),
+            OutboundTarget::AllShards,
+        );
+        Transport::installed()
+            .ok_or_else(|| miette!("no transport has been installed"))?
+            .publish_event(
+                Channel::OutboundCommunication,
+                &InternalEvent::OutboundGatewayCommand(envelope),
+            )
+            .await?;
+
+        context
+            .respond(&ephemeral_response(
+                localizer.support_plugin_presence_updated()?,
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -30,7 +30,7 @@ use std::time::SystemTime;
 use hartex_backend_models::Response;
 use hartex_backend_models::uptime::UptimeQuery;
 use hartex_backend_models::uptime::UptimeResponse;
-use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_commands_core::response::ResponseContext;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
 use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
@@ -54,8 +54,8 @@ use miette::Report;
 
 /// Executes the `info bot` command
 pub async fn execute(
-    interaction: Interaction,
-    interaction_client: &InteractionClient<'_>,
+    _: Interaction,
+    context: &ResponseContext<'_>,
     _: CommandDataOption,
     localizer: Localizer<'_>,
 ) -> miette::Result<()> {
@@ -119,14 +119,7 @@ pub async fn execute(
         .into_diagnostic()?
         .build();
 
-    interaction_client
-        .create_response(
-            interaction.id,
-            &interaction.token,
-            &embed_response(vec![embed]),
-        )
-        .await
-        .into_diagnostic()?;
+    context.respond(&embed_response(vec![embed])).await?;
 
     Ok(())
 }
@@ -26,7 +26,9 @@
 
 use std::str::FromStr;
 use std::sync::LazyLock;
-use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_core::discord::model::application::command::CommandOptionChoice;
+use hartex_discord_core::discord::model::application::command::CommandOptionChoiceValue;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
 use hartex_discord_core::discord::model::id::Id;
@@ -36,8 +38,10 @@ use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
 use hartex_discord_entitycache_core::error::CacheError;
 use hartex_discord_entitycache_core::traits::Repository;
 use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
 use hartex_discord_utils::commands::CommandDataOptionExt;
 use hartex_discord_utils::commands::CommandDataOptionsExt;
+use hartex_discord_utils::interaction::autocomplete_response;
 use hartex_discord_utils::interaction::embed_response;
 use hartex_discord_utils::interaction::ephemeral_error_response;
 use hartex_discord_utils::localizable::Localizable;
@@ -51,11 +55,54 @@ static EMOJI_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new("<a?:[a-zA-Z0-9_]+:([0-9]{17,19})>").unwrap()
 });
 
+/// The maximum number of choices Discord accepts in an autocomplete result.
+const MAX_AUTOCOMPLETE_CHOICES: usize = 25;
+
+/// Suggests custom emojis of the current server for the `emoji` option of the `info emoji`
+/// command.
+pub async fn autocomplete(
+    interaction: Interaction,
+    context: &ResponseContext<'_>,
+    option: CommandDataOption,
+) -> miette::Result<()> {
+    let options = option.assume_subcommand();
+    let query = options
+        .focused_option()
+        .map(|(_, value)| value.to_lowercase())
+        .unwrap_or_default();
+
+    let guild = CachedGuildRepository
+        .get(interaction.guild_id.unwrap())
+        .await
+        .into_diagnostic()?;
+    let emojis = guild.emojis(guild.id).await.into_diagnostic()?;
+
+    let choices = emojis
+        .into_iter()
+        .filter(|emoji| emoji.name.to_lowercase().contains(&query))
+        .take(MAX_AUTOCOMPLETE_CHOICES)
+        .map(|emoji| CommandOptionChoice {
+            name: format!(":{}:", emoji.name),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(format!(
+                "<{}:{}:{}>",
+                if emoji.animated { "a" } else { "" },
+                emoji.name,
+                emoji.id
+            )),
+        })
+        .collect();
+
+    context.respond(&autocomplete_response(choices)).await?;
+
+    Ok(())
+}
+
 /// Executes the `info emoji` command.
 #[allow(clippy::too_many_lines)]
 pub async fn execute(
     interaction: Interaction,
-    interaction_client: &InteractionClient<'_>,
+    context: &ResponseContext<'_>,
     option: CommandDataOption,
     localizer: Localizer<'_>,
 ) -> miette::Result<()> {
@@ -76,27 +123,19 @@ pub async fn execute(
         localizer.utilities_plugin_emojiinfo_error_unknown_emoji()?;
 
     let Some(captures) = EMOJI_REGEX.captures(&emoji) else {
-        interaction_client
-            .create_response(
-                interaction.id,
-                &interaction.token,
-                &ephemeral_error_response(emojiinfo_error_only_custom_emojis),
-            )
-            .await
-            .into_diagnostic()?;
+        context
+            .respond(&ephemeral_error_response(
+                emojiinfo_error_only_custom_emojis,
+            ))
+            .await?;
 
         return Ok(());
     };
 
     if captures.len() > 2 {
-        interaction_client
-            .create_response(
-                interaction.id,
-                &interaction.token,
-                &ephemeral_error_response(emojiinfo_error_only_one_emoji),
-            )
-            .await
-            .into_diagnostic()?;
+        context
+            .respond(&ephemeral_error_response(emojiinfo_error_only_one_emoji))
+            .await?;
 
         return Ok(());
     }
@@ -107,15 +146,10 @@ pub async fn execute(
     let result = CachedEmojiRepository.get(emoji_id).await;
     let emoji = match result {
         Ok(emoji) => emoji,
-        Err(CacheError::Database(_)) /*if postgres_error.is(SqlState::NO_DATA)*/ => {
-            interaction_client
-                .create_response(
-                    interaction.id,
-                    &interaction.token,
-                    &ephemeral_error_response(emojiinfo_error_unknown_emoji),
-                )
-                .await
-                .into_diagnostic()?;
+        Err(CacheError::NotFound) => {
+            context
+                .respond(&ephemeral_error_response(emojiinfo_error_unknown_emoji))
+                .await?;
 
             return Ok(());
         }
@@ -157,14 +191,7 @@ pub async fn execute(
         .into_diagnostic()?
         .build();
 
-    interaction_client
-        .create_response(
-            interaction.id,
-            &interaction.token,
-            &embed_response(vec![embed]),
-        )
-        .await
-        .into_diagnostic()?;
+    context.respond(&embed_response(vec![embed])).await?;
 
     Ok(())
 }
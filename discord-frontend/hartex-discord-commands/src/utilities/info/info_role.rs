@@ -25,7 +25,7 @@
 //! This command returns informatiomn about a role.
 
 use hartex_discord_cdn::Cdn;
-use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_commands_core::response::ResponseContext;
 use hartex_discord_core::discord::mention::Mention;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
@@ -46,7 +46,7 @@ use miette::IntoDiagnostic;
 /// Executes the `info emoji` command.
 pub async fn execute(
     interaction: Interaction,
-    interaction_client: &InteractionClient<'_>,
+    context: &ResponseContext<'_>,
     option: CommandDataOption,
     localizer: Localizer<'_>,
 ) -> miette::Result<()> {
@@ -124,14 +124,7 @@ pub async fn execute(
 
     let embed = builder.validate().into_diagnostic()?.build();
 
-    interaction_client
-        .create_response(
-            interaction.id,
-            &interaction.token,
-            &embed_response(vec![embed]),
-        )
-        .await
-        .into_diagnostic()?;
+    context.respond(&embed_response(vec![embed])).await?;
 
     Ok(())
 }
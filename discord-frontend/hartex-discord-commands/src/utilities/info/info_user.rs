@@ -25,10 +25,12 @@
 //! This command returns informatiomn about a user.
 
 use hartex_discord_cdn::Cdn;
-use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_commands_core::response::ResponseContext;
 use hartex_discord_core::discord::mention::Mention;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
 use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
 use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
 use hartex_discord_core::discord::util::builder::embed::ImageSource;
@@ -46,10 +48,9 @@ use rand::seq::IndexedRandom;
 use rand::thread_rng;
 
 /// Executes the `info user` command.
-#[allow(clippy::too_many_lines)]
 pub async fn execute(
     interaction: Interaction,
-    interaction_client: &InteractionClient<'_>,
+    context: &ResponseContext<'_>,
     option: CommandDataOption,
     localizer: Localizer<'_>,
 ) -> miette::Result<()> {
@@ -57,6 +58,17 @@ pub async fn execute(
 
     let user_id = options.user_value_of("user");
 
+    respond_with_user_info(interaction, context, user_id, localizer).await
+}
+
+/// Responds with information about a user.
+#[allow(clippy::too_many_lines)]
+pub async fn respond_with_user_info(
+    interaction: Interaction,
+    context: &ResponseContext<'_>,
+    user_id: Id<UserMarker>,
+    localizer: Localizer<'_>,
+) -> miette::Result<()> {
     let user = CachedUserRepository.get(user_id).await.into_diagnostic()?;
 
     let userinfo_embed_generalinfo_field_name =
@@ -157,14 +169,7 @@ pub async fn execute(
 
     let embed = builder.validate().into_diagnostic()?.build();
 
-    interaction_client
-        .create_response(
-            interaction.id,
-            &interaction.token,
-            &embed_response(vec![embed]),
-        )
-        .await
-        .into_diagnostic()?;
+    context.respond(&embed_response(vec![embed])).await?;
 
     Ok(())
 }
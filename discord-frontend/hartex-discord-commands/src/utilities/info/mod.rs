@@ -24,8 +24,8 @@
 
 use async_trait::async_trait;
 use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::response::ResponseContext;
 use hartex_discord_commands_core::traits::Command;
-use hartex_discord_core::discord::http::client::InteractionClient;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
 use hartex_discord_core::discord::model::application::interaction::InteractionData;
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
@@ -39,8 +39,10 @@ mod info_role;
 mod info_server;
 mod info_user;
 
+pub(crate) use info_user::respond_with_user_info;
+
 /// The `info` command declaration.
-#[command(name = "info", plugin = Utilities)]
+#[command(name = "info", plugin = Utilities, cooldown = user(3))]
 pub struct Info;
 
 #[async_trait]
@@ -48,7 +50,7 @@ impl Command for Info {
     async fn execute(
         &self,
         interaction: Interaction,
-        interaction_client: &InteractionClient<'_>,
+        context: &ResponseContext<'_>,
         localizer: Localizer<'_>,
     ) -> miette::Result<()> {
         let Some(InteractionData::ApplicationCommand(command)) = interaction.clone().data else {
@@ -64,51 +66,39 @@ impl Command for Info {
         };
 
         match subcommand.name.as_str() {
-            "bot" => {
-                info_bot::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
-            }
+            "bot" => info_bot::execute(interaction, context, subcommand.clone(), localizer).await,
             "emoji" => {
-                info_emoji::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
-            }
-            "role" => {
-                info_role::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
+                info_emoji::execute(interaction, context, subcommand.clone(), localizer).await
             }
+            "role" => info_role::execute(interaction, context, subcommand.clone(), localizer).await,
             "server" => {
-                info_server::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
-            }
-            "user" => {
-                info_user::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
+                info_server::execute(interaction, context, subcommand.clone(), localizer).await
             }
+            "user" => info_user::execute(interaction, context, subcommand.clone(), localizer).await,
+            _ => unreachable!(),
+        }
+    }
+
+    async fn autocomplete(
+        &self,
+        interaction: Interaction,
+        context: &ResponseContext<'_>,
+        _: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::ApplicationCommand(command)) = interaction.clone().data else {
+            unreachable!()
+        };
+
+        let Some(subcommand) = command
+            .options
+            .iter()
+            .find(|option| matches!(option.value, CommandOptionValue::SubCommand(_)))
+        else {
+            unreachable!()
+        };
+
+        match subcommand.name.as_str() {
+            "emoji" => info_emoji::autocomplete(interaction, context, subcommand.clone()).await,
             _ => unreachable!(),
         }
     }
@@ -24,16 +24,29 @@
 //!
 //! Command List:
 //! - info
+//! - permissions
+//! - Report message
+//! - User info
 
 use async_trait::async_trait;
 use hartex_discord_commands_core::plugin;
 use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_core::discord::model::gateway::Intents;
 
 pub mod info;
+pub mod permissions;
+pub mod report_message;
+pub mod user_info;
 
 /// The utilities plugin.
 #[plugin(name = "utilities")]
 pub struct Utilities;
 
 #[async_trait]
-impl Plugin for Utilities {}
+impl Plugin for Utilities {
+    fn intents(&self) -> Intents {
+        // the info commands and permission checks read guilds, channels, roles, members and
+        // emojis from the entity cache
+        Intents::GUILDS | Intents::GUILD_MEMBERS | Intents::GUILD_EMOJIS_AND_STICKERS
+    }
+}
@@ -0,0 +1,79 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Permissions Command
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::registry;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_commands_core::traits::CommandMetadata;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
+use hartex_localization_core::Localizer;
+
+use crate::utilities::Utilities;
+
+mod permissions_check;
+
+/// The `permissions` command declaration.
+///
+/// This is not named `Permissions` as to not clash with the permissions bitflags.
+#[command(name = "permissions", plugin = Utilities, required_permissions = Permissions::MANAGE_GUILD)]
+pub struct PermissionsCommand;
+
+#[async_trait]
+impl Command for PermissionsCommand {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        context: &ResponseContext<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::ApplicationCommand(command)) = interaction.clone().data else {
+            unreachable!()
+        };
+
+        let Some(subcommand) = command
+            .options
+            .iter()
+            .find(|option| matches!(option.value, CommandOptionValue::SubCommand(_)))
+        else {
+            unreachable!()
+        };
+
+        match subcommand.name.as_str() {
+            "check" => {
+                permissions_check::execute(interaction, context, subcommand.clone(), localizer)
+                    .await
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Returns the registered command with the given name, if any.
+fn registered_command(name: &str) -> Option<Box<dyn Command + Send + Sync>> {
+    registry::commands().find(|command| command.name() == name)
+}
@@ -0,0 +1,247 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Permissions Check Subcommand
+//!
+//! This command checks whether a user may run a command in a channel, taking the permission
+//! overrides configured in the guild into account.
+
+use hartex_discord_commands_core::permissions::CommandPermissions;
+use hartex_discord_commands_core::permissions::PermissionCheck;
+use hartex_discord_commands_core::permissions::PermissionSubject;
+use hartex_discord_commands_core::permissions::PermissionTarget;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
+use hartex_discord_core::discord::model::guild::Permissions;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_core::discord::util::permission_calculator::PermissionCalculator;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::channel::CachedChannelRepository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+use hartex_discord_utils::commands::CommandDataOptionExt;
+use hartex_discord_utils::commands::CommandDataOptionsExt;
+use hartex_discord_utils::interaction::embed_response;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+/// Executes the `permissions check` command.
+pub async fn execute(
+    interaction: Interaction,
+    context: &ResponseContext<'_>,
+    option: CommandDataOption,
+    localizer: Localizer<'_>,
+) -> miette::Result<()> {
+    let Some(InteractionData::ApplicationCommand(command)) = interaction.clone().data else {
+        unreachable!()
+    };
+
+    let options = option.assume_subcommand();
+    let command_name = options.string_value_of("command");
+
+    let Some(metadata) = super::registered_command(&command_name) else {
+        context
+            .respond(&ephemeral_error_response(
+                localizer.utilities_plugin_permissionscheck_error_unknown_command(command_name)?,
+            ))
+            .await?;
+
+        return Ok(());
+    };
+
+    let (Some(guild_id), Some(mut subject)) = (
+        interaction.guild_id,
+        PermissionSubject::from_interaction(&interaction),
+    ) else {
+        context
+            .respond(&ephemeral_error_response(
+                localizer.error_error_guild_only()?,
+            ))
+            .await?;
+
+        return Ok(());
+    };
+
+    if let Some(CommandOptionValue::User(user_id)) = options
+        .iter()
+        .find(|option| option.name == "user")
+        .map(|option| option.value.clone())
+    {
+        let Some(member) = command
+            .resolved
+            .as_ref()
+            .and_then(|resolved| resolved.members.get(&user_id))
+        else {
+            context
+                .respond(&ephemeral_error_response(
+                    localizer.utilities_plugin_permissionscheck_error_not_a_member()?,
+                ))
+                .await?;
+
+            return Ok(());
+        };
+
+        subject.user_id = user_id;
+        subject.roles = PermissionSubject::roles_with_everyone(guild_id, &member.roles);
+        subject.permissions = member.permissions;
+    }
+
+    // the permissions resolved with the interaction are those in the channel it is invoked in, so
+    // they are recomputed when checking against another channel
+    if let Some(CommandOptionValue::Channel(channel_id)) = options
+        .iter()
+        .find(|option| option.name == "channel")
+        .map(|option| option.value.clone())
+    {
+        subject.channel_id.replace(channel_id);
+        subject.permissions = channel_permissions(guild_id, &subject, channel_id).await?;
+    }
+
+    let check = CommandPermissions::load(guild_id, &command_name)
+        .await?
+        .evaluate(&subject, metadata.required_permissions());
+
+    let permissionscheck_embed_description = localizer
+        .utilities_plugin_permissionscheck_embed_description(
+            subject
+                .channel_id
+                .map(|channel_id| channel_id.mention().to_string())
+                .unwrap_or_default(),
+            format!("/{command_name}").discord_inline_code(),
+            subject.user_id.mention().to_string(),
+        )?;
+    let permissionscheck_embed_result_field_name =
+        localizer.utilities_plugin_permissionscheck_embed_result_field_name()?;
+    let permissionscheck_result = if check.allowed() {
+        localizer.utilities_plugin_permissionscheck_result_allowed()?
+    } else {
+        localizer.utilities_plugin_permissionscheck_result_denied()?
+    };
+    let permissionscheck_reason =
+        match check {
+            PermissionCheck::AllowedByRule(target) => localizer
+                .utilities_plugin_permissionscheck_reason_allowed_by_rule(mention(target))?,
+            PermissionCheck::DeniedByRule(target) => localizer
+                .utilities_plugin_permissionscheck_reason_denied_by_rule(mention(target))?,
+            PermissionCheck::AllowedByPermissions => {
+                localizer.utilities_plugin_permissionscheck_reason_allowed_by_permissions()?
+            }
+            PermissionCheck::DeniedByPermissions(missing) => localizer
+                .utilities_plugin_permissionscheck_reason_denied_by_permissions(
+                    missing
+                        .iter_names()
+                        .map(|(name, _)| name.to_string().discord_inline_code())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                )?,
+        };
+
+    let embed = EmbedBuilder::new()
+        .color(0x41_A0_DE)
+        .description(permissionscheck_embed_description)
+        .field(EmbedFieldBuilder::new(
+            permissionscheck_embed_result_field_name,
+            format!("{permissionscheck_result}\n{permissionscheck_reason}"),
+        ))
+        .validate()
+        .into_diagnostic()?
+        .build();
+
+    context.respond(&embed_response(vec![embed])).await?;
+
+    Ok(())
+}
+
+/// Computes the permissions of a subject in a channel from the cached roles of the guild and the
+/// permission overwrites of the channel.
+async fn channel_permissions(
+    guild_id: Id<GuildMarker>,
+    subject: &PermissionSubject,
+    channel_id: Id<ChannelMarker>,
+) -> miette::Result<Permissions> {
+    let guild = CachedGuildRepository
+        .get(guild_id)
+        .await
+        .into_diagnostic()?;
+    let role_ids = subject
+        .roles
+        .iter()
+        .map(|role_id| (guild_id, *role_id))
+        .collect::<Vec<_>>();
+    let roles = CachedRoleRepository
+        .get_many(&role_ids)
+        .await
+        .into_diagnostic()?;
+
+    let everyone_permissions = roles
+        .iter()
+        .find(|role| role.id == guild_id.cast())
+        .map_or_else(Permissions::empty, |role| role.permissions);
+    let member_roles = roles
+        .iter()
+        .filter(|role| role.id != guild_id.cast())
+        .map(|role| (role.id, role.permissions))
+        .collect::<Vec<_>>();
+
+    // threads inherit the permission overwrites of their parent channel
+    let mut channel = CachedChannelRepository
+        .get(channel_id)
+        .await
+        .into_diagnostic()?;
+    let kind = channel.kind;
+    if kind.is_thread()
+        && let Some(parent_id) = channel.parent_id
+    {
+        channel = CachedChannelRepository
+            .get(parent_id)
+            .await
+            .into_diagnostic()?;
+    }
+
+    Ok(PermissionCalculator::new(
+        guild_id,
+        subject.user_id,
+        everyone_permissions,
+        &member_roles,
+    )
+    .owner_id(guild.owner_id)
+    .in_channel(kind, &channel.permission_overwrites.unwrap_or_default()))
+}
+
+/// Mentions the target of a permission rule.
+fn mention(target: PermissionTarget) -> String {
+    match target {
+        PermissionTarget::Role(role_id) => role_id.mention().to_string(),
+        PermissionTarget::User(user_id) => user_id.mention().to_string(),
+        PermissionTarget::Channel(channel_id) => channel_id.mention().to_string(),
+    }
+}
@@ -0,0 +1,186 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Report Message Command
+//!
+//! This message command allows members to report the message it is invoked on to the moderators
+//! of the server, by sending it to the reports channel configured for the server.
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::components::CustomId;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_commands_core::targets::MessageTarget;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_commands_core::traits::CommandMetadata;
+use hartex_discord_commands_core::traits::PluginMetadata;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::channel::message::component::TextInput;
+use hartex_discord_core::discord::model::channel::message::component::TextInputStyle;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::MessageMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_utils::CLIENT;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::interaction::ephemeral_response;
+use hartex_discord_utils::interaction::modal_response;
+use hartex_discord_utils::modals::ModalInteractionDataExt;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+use crate::utilities::Utilities;
+
+/// The custom ID of the text input for the reason of a report.
+const REASON_TEXT_INPUT: &str = "reason";
+
+/// The `Report message` command declaration.
+#[command(name = "Report message", plugin = Utilities, kind = message, cooldown = user(30))]
+pub struct ReportMessage;
+
+#[async_trait]
+impl Command for ReportMessage {
+    async fn execute_message(
+        &self,
+        interaction: Interaction,
+        target: MessageTarget,
+        context: &ResponseContext<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        if ConfigurationProvider::reports_channel(interaction.guild_id.unwrap())
+            .await?
+            .is_none()
+        {
+            context
+                .respond(&ephemeral_error_response(
+                    localizer.utilities_plugin_reportmessage_error_not_configured()?,
+                ))
+                .await?;
+
+            return Ok(());
+        }
+
+        let message = target.message;
+        let custom_id = CustomId::new(self.plugin().name(), self.name(), "submit").state(format!(
+            "{}:{}:{}",
+            message.channel_id, message.id, message.author.id
+        ));
+
+        context
+            .respond(&modal_response(
+                custom_id.to_string(),
+                localizer.utilities_plugin_reportmessage_modal_title()?,
+                vec![TextInput {
+                    custom_id: String::from(REASON_TEXT_INPUT),
+                    label: localizer.utilities_plugin_reportmessage_modal_reason_label()?,
+                    max_length: Some(1000),
+                    min_length: None,
+                    placeholder: None,
+                    required: Some(true),
+                    style: TextInputStyle::Paragraph,
+                    value: None,
+                }],
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn modal_submit(
+        &self,
+        interaction: Interaction,
+        context: &ResponseContext<'_>,
+        custom_id: CustomId,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::ModalSubmit(modal)) = interaction.data.clone() else {
+            unreachable!()
+        };
+
+        let guild_id = interaction.guild_id.unwrap();
+        let Some(reports_channel_id) = ConfigurationProvider::reports_channel(guild_id).await?
+        else {
+            context
+                .respond(&ephemeral_error_response(
+                    localizer.utilities_plugin_reportmessage_error_not_configured()?,
+                ))
+                .await?;
+
+            return Ok(());
+        };
+
+        let state = custom_id.state.unwrap_or_default();
+        let mut segments = state.split(':');
+        let (Some(channel_id), Some(message_id), Some(author_id)) =
+            (segments.next(), segments.next(), segments.next())
+        else {
+            miette::bail!("malformed report message state: {state}");
+        };
+        let channel_id = channel_id.parse::<Id<ChannelMarker>>().into_diagnostic()?;
+        let message_id = message_id.parse::<Id<MessageMarker>>().into_diagnostic()?;
+        let author_id = author_id.parse::<Id<UserMarker>>().into_diagnostic()?;
+
+        let reason = modal.text_input_value_of(REASON_TEXT_INPUT);
+
+        let embed = EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .title(localizer.utilities_plugin_reportmessage_embed_title()?)
+            .field(EmbedFieldBuilder::new(
+                localizer.utilities_plugin_reportmessage_embed_message_field_name()?,
+                format!("https://discord.com/channels/{guild_id}/{channel_id}/{message_id}"),
+            ))
+            .field(EmbedFieldBuilder::new(
+                localizer.utilities_plugin_reportmessage_embed_author_field_name()?,
+                author_id.mention().to_string(),
+            ))
+            .field(EmbedFieldBuilder::new(
+                localizer.utilities_plugin_reportmessage_embed_reporter_field_name()?,
+                interaction.author_id().unwrap().mention().to_string(),
+            ))
+            .field(EmbedFieldBuilder::new(
+                localizer.utilities_plugin_reportmessage_embed_reason_field_name()?,
+                reason,
+            ))
+            .validate()
+            .into_diagnostic()?
+            .build();
+
+        CLIENT
+            .create_message(reports_channel_id)
+            .embeds(&[embed])
+            .await
+            .into_diagnostic()?;
+
+        context
+            .respond(&ephemeral_response(
+                localizer.utilities_plugin_reportmessage_success()?,
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The User Info Command
+//!
+//! This user command returns information about the user it is invoked on, the same way as the
+//! `info user` command does.
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_commands_core::targets::UserTarget;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_localization_core::Localizer;
+
+use crate::utilities::Utilities;
+use crate::utilities::info::respond_with_user_info;
+
+/// The `User info` command declaration.
+#[command(name = "User info", plugin = Utilities, kind = user, cooldown = user(3))]
+pub struct UserInfo;
+
+#[async_trait]
+impl Command for UserInfo {
+    async fn execute_user(
+        &self,
+        interaction: Interaction,
+        target: UserTarget,
+        context: &ResponseContext<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        respond_with_user_info(interaction, context, target.user.id, localizer).await
+    }
+}
@@ -35,6 +35,31 @@ const SAMPLE_CONFIG: &'static str = r#"return {
         colour = hartexconf.colour.rgb(0x768EE5)
     },
 
+    cooldowns = {
+        info = {
+            bucket = "channel",
+            seconds = 10
+        }
+    },
+
+    permissions = {
+        info = {
+            replace_required_permissions = true,
+            rules = {
+                {
+                    kind = "role",
+                    id = "1000000000000007",
+                    allow = true
+                },
+                {
+                    kind = "channel",
+                    id = "1000000000000008",
+                    allow = false
+                }
+            }
+        }
+    },
+
     plugins = {
         management = {
             enabled = true
@@ -50,7 +75,8 @@ const SAMPLE_CONFIG: &'static str = r#"return {
             }
         },
         utilities = {
-            enabled = true
+            enabled = true,
+            reports_channel = "1000000000000009"
         }
     }
 }
@@ -72,6 +98,14 @@ Configuration {
             ),
         },
     ),
+    cooldowns: Some(
+        {
+            "info": CommandCooldown {
+                bucket: Channel,
+                seconds: 10,
+            },
+        },
+    ),
     dashboard: Dashboard {
         admins: [
             "1000000000000000",
@@ -90,6 +124,25 @@ Configuration {
             ],
         ),
     },
+    permissions: Some(
+        {
+            "info": CommandPermissions {
+                replace_required_permissions: true,
+                rules: [
+                    PermissionRule {
+                        kind: Role,
+                        id: "1000000000000007",
+                        allow: true,
+                    },
+                    PermissionRule {
+                        kind: Channel,
+                        id: "1000000000000008",
+                        allow: false,
+                    },
+                ],
+            },
+        },
+    ),
     plugins: Some(
         Plugins {
             management: Some(
@@ -114,6 +167,9 @@ Configuration {
             utilities: Some(
                 UtilitiesPlugin {
                     enabled: true,
+                    reports_channel: Some(
+                        "1000000000000009",
+                    ),
                 },
             ),
         },
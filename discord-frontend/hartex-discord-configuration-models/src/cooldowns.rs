@@ -0,0 +1,124 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Cooldown Configuration Object
+
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::id::Id;
+use mlua::Error;
+use mlua::FromLua;
+use mlua::Lua;
+use mlua::Value;
+use serde::Serialize;
+
+/// The command cooldown configuration object, overriding the cooldown declared by a command.
+#[derive(Debug, Serialize)]
+pub struct CommandCooldown {
+    /// The bucket the cooldown is tracked in.
+    pub bucket: CooldownBucket,
+    /// The duration of the cooldown in seconds; a value of zero disables the cooldown.
+    pub seconds: u32,
+}
+
+impl FromLua for CommandCooldown {
+    fn from_lua(lua_value: Value, _: &Lua) -> mlua::Result<Self> {
+        let Value::Table(table) = lua_value.clone() else {
+            return Err(Error::RuntimeError(format!(
+                "CommandCooldown: mismatched value type, expected table, found: {}",
+                lua_value.type_name()
+            )));
+        };
+
+        let bucket = table.get("bucket")?;
+        let seconds = table.get("seconds")?;
+
+        Ok(Self { bucket, seconds })
+    }
+}
+
+/// The bucket a cooldown is tracked in.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum CooldownBucket {
+    /// The cooldown is tracked per user.
+    User,
+    /// The cooldown is tracked per channel.
+    Channel,
+    /// The cooldown is tracked per guild.
+    Guild,
+}
+
+impl CooldownBucket {
+    /// Returns the bucket with the given name as used in guild configurations, if any.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "user" => Some(Self::User),
+            "channel" => Some(Self::Channel),
+            "guild" => Some(Self::Guild),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the bucket as used in guild configurations.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Channel => "channel",
+            Self::Guild => "guild",
+        }
+    }
+
+    /// Returns the ID of the bucket an interaction falls into, if any.
+    #[must_use]
+    pub fn key(self, interaction: &Interaction) -> Option<u64> {
+        match self {
+            Self::User => interaction.author_id().map(Id::get),
+            Self::Channel => interaction.channel.as_ref().map(|channel| channel.id.get()),
+            Self::Guild => interaction.guild_id.map(Id::get),
+        }
+    }
+}
+
+impl FromLua for CooldownBucket {
+    fn from_lua(lua_value: Value, _: &Lua) -> mlua::Result<Self> {
+        let Value::String(string) = lua_value.clone() else {
+            return Err(Error::RuntimeError(format!(
+                "CooldownBucket: mismatched value type, expected string, found: {}",
+                lua_value.type_name()
+            )));
+        };
+
+        let Ok(rust_string) = string.to_str() else {
+            return Err(Error::RuntimeError(String::from(
+                "CooldownBucket: string contains invalid UTF-8",
+            )));
+        };
+
+        Self::from_name(&rust_string).ok_or_else(|| {
+            Error::RuntimeError(format!(
+                "CooldownBucket: unexpected variant: {}, expected either user, channel or guild",
+                &*rust_string
+            ))
+        })
+    }
+}
@@ -30,6 +30,8 @@
 #![deny(warnings)]
 #![feature(result_flattening)]
 
+use std::collections::BTreeMap;
+
 use mlua::Error;
 use mlua::FromLuaMulti;
 use mlua::Lua;
@@ -38,16 +40,22 @@ use mlua::Value;
 use serde::Serialize;
 
 pub mod appearance;
+pub mod cooldowns;
 pub mod dashboard;
 pub mod eventflags;
+pub mod permissions;
 pub mod plugins;
 
 #[derive(Debug, Serialize)]
 pub struct Configuration {
     /// An optional appearance configuration object.
     pub appearance: Option<appearance::Appearance>,
+    /// An optional mapping of command names to cooldown overrides.
+    pub cooldowns: Option<BTreeMap<String, cooldowns::CommandCooldown>>,
     /// A dashboard configuration object.
     pub dashboard: dashboard::Dashboard,
+    /// An optional mapping of command names to command permission overrides.
+    pub permissions: Option<BTreeMap<String, permissions::CommandPermissions>>,
     /// An optional plugins configuration object.
     pub plugins: Option<plugins::Plugins>,
 }
@@ -69,12 +77,16 @@ impl FromLuaMulti for Configuration {
         };
 
         let appearance = value.get("appearance")?;
+        let cooldowns = value.get("cooldowns")?;
         let dashboard = value.get("dashboard")?;
+        let permissions = value.get("permissions")?;
         let plugins = value.get("plugins")?;
 
         Ok(Self {
             appearance,
+            cooldowns,
             dashboard,
+            permissions,
             plugins,
         })
     }
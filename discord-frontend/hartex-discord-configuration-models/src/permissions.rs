@@ -0,0 +1,139 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Permissions Configuration Object
+
+use mlua::Error;
+use mlua::FromLua;
+use mlua::Lua;
+use mlua::Value;
+use serde::Serialize;
+
+/// The command permissions configuration object, allowing or denying a command for specific roles,
+/// users and channels.
+#[derive(Debug, Serialize)]
+pub struct CommandPermissions {
+    /// Whether an allowing rule replaces the permissions the command requires by default, instead
+    /// of being checked in addition to them.
+    pub replace_required_permissions: bool,
+    /// The permission rules of the command.
+    pub rules: Vec<PermissionRule>,
+}
+
+impl FromLua for CommandPermissions {
+    fn from_lua(lua_value: Value, _: &Lua) -> mlua::Result<Self> {
+        let Value::Table(table) = lua_value.clone() else {
+            return Err(Error::RuntimeError(format!(
+                "CommandPermissions: mismatched value type, expected table, found: {}",
+                lua_value.type_name()
+            )));
+        };
+
+        let replace_required_permissions = table.get("replace_required_permissions")?;
+        let rules = table.get("rules")?;
+
+        Ok(Self {
+            replace_required_permissions,
+            rules,
+        })
+    }
+}
+
+/// The permission rule configuration object.
+#[derive(Debug, Serialize)]
+pub struct PermissionRule {
+    /// The kind of the target of the rule.
+    pub kind: PermissionRuleKind,
+    /// The ID of the target of the rule.
+    pub id: String,
+    /// Whether the rule allows or denies the command.
+    pub allow: bool,
+}
+
+impl FromLua for PermissionRule {
+    fn from_lua(lua_value: Value, _: &Lua) -> mlua::Result<Self> {
+        let Value::Table(table) = lua_value.clone() else {
+            return Err(Error::RuntimeError(format!(
+                "PermissionRule: mismatched value type, expected table, found: {}",
+                lua_value.type_name()
+            )));
+        };
+
+        let kind = table.get("kind")?;
+        let id = table.get("id")?;
+        let allow = table.get("allow")?;
+
+        Ok(Self { kind, id, allow })
+    }
+}
+
+/// Configures the kinds of permission rule targets.
+#[derive(Debug, Serialize)]
+pub enum PermissionRuleKind {
+    /// The rule targets a role.
+    Role,
+    /// The rule targets a user.
+    User,
+    /// The rule targets a channel.
+    Channel,
+}
+
+impl PermissionRuleKind {
+    /// Returns the name of the kind as used in guild configurations.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Role => "role",
+            Self::User => "user",
+            Self::Channel => "channel",
+        }
+    }
+}
+
+impl FromLua for PermissionRuleKind {
+    fn from_lua(lua_value: Value, _: &Lua) -> mlua::Result<Self> {
+        let Value::String(string) = lua_value.clone() else {
+            return Err(Error::RuntimeError(format!(
+                "PermissionRuleKind: mismatched value type, expected string, found: {}",
+                lua_value.type_name()
+            )));
+        };
+
+        let Ok(rust_string) = string.to_str() else {
+            return Err(Error::RuntimeError(String::from(
+                "PermissionRuleKind: string contains invalid UTF-8",
+            )));
+        };
+
+        Ok(match &*rust_string {
+            "role" => Self::Role,
+            "user" => Self::User,
+            "channel" => Self::Channel,
+            _ => {
+                return Err(Error::RuntimeError(format!(
+                    "PermissionRuleKind: unexpected variant: {}, expected either role, user or channel",
+                    &*rust_string
+                )));
+            }
+        })
+    }
+}
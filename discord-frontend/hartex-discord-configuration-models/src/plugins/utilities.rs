@@ -34,6 +34,8 @@ use serde::Serialize;
 pub struct UtilitiesPlugin {
     /// Sets whether the utilities plugin is enabled.
     pub enabled: bool,
+    /// The channel ID messages reported by members are sent to.
+    pub reports_channel: Option<String>,
 }
 
 impl FromLua for UtilitiesPlugin {
@@ -46,7 +48,11 @@ impl FromLua for UtilitiesPlugin {
         };
 
         let enabled = table.get("enabled")?;
+        let reports_channel = table.get("reports_channel")?;
 
-        Ok(Self { enabled })
+        Ok(Self {
+            enabled,
+            reports_channel,
+        })
     }
 }
@@ -22,21 +22,99 @@
 
 //! # Configuration Provider
 //!
-//! This crate contains APIs for easier retrieval of specific parts of a configuration for servers.
+//! This crate contains APIs for easier retrieval of specific parts of a configuration for servers,
+//! as well as for storing the parts of an evaluated configuration that are queried by the bot.
 
 #![deny(clippy::pedantic)]
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+use hartex_database_queries::queries::configuration::command_cooldown_delete_by_guild_id_except_commands::CommandCooldownDeleteByGuildIdExceptCommands;
+use hartex_database_queries::queries::configuration::command_cooldown_select_by_guild_id_and_command::CommandCooldownSelectByGuildIdAndCommand;
+use hartex_database_queries::queries::configuration::command_cooldown_upsert_many::CommandCooldownUpsertMany;
+use hartex_database_queries::queries::configuration::command_permission_rules_delete_by_guild_id_except_rules::CommandPermissionRulesDeleteByGuildIdExceptRules;
+use hartex_database_queries::queries::configuration::command_permission_rules_select_by_guild_id_and_command::CommandPermissionRulesSelectByGuildIdAndCommand;
+use hartex_database_queries::queries::configuration::command_permission_rules_upsert_many::CommandPermissionRulesUpsertMany;
+use hartex_database_queries::queries::configuration::command_permissions_delete_by_guild_id_except_commands::CommandPermissionsDeleteByGuildIdExceptCommands;
+use hartex_database_queries::queries::configuration::command_permissions_select_by_guild_id_and_command::CommandPermissionsSelectByGuildIdAndCommand;
+use hartex_database_queries::queries::configuration::command_permissions_upsert_many::CommandPermissionsUpsertMany;
 use hartex_database_queries::queries::configuration::plugin_enabled::PluginEnabled;
+use hartex_database_queries::queries::configuration::utilities_plugin_configuration_delete_by_guild_id::UtilitiesPluginConfigurationDeleteByGuildId;
+use hartex_database_queries::queries::configuration::utilities_plugin_configuration_select_by_guild_id::UtilitiesPluginConfigurationSelectByGuildId;
+use hartex_database_queries::queries::configuration::utilities_plugin_configuration_upsert::UtilitiesPluginConfigurationUpsert;
+use hartex_database_queries::tables::configuration::NightlyCommandCooldowns;
+use hartex_database_queries::tables::configuration::NightlyCommandPermissionRules;
+use hartex_database_queries::tables::configuration::NightlyCommandPermissions;
+use hartex_discord_configuration_models::Configuration;
 use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
 use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_discord_internal_events::configuration::ConfigurationChanged;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
 use miette::IntoDiagnostic;
+use serde_json::Value;
+use serde_json::json;
 
 /// The configuration provide for fetching configuration.
 pub struct ConfigurationProvider;
 
 impl ConfigurationProvider {
+    /// Queries the cooldown override configured for a specific command in a certain guild, if any.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn command_cooldown(
+        guild_id: Id<GuildMarker>,
+        command: impl Into<String>,
+    ) -> miette::Result<Option<NightlyCommandCooldowns>> {
+        Ok(
+            CommandCooldownSelectByGuildIdAndCommand::bind(guild_id.to_string(), command.into())
+                .executor()
+                .await
+                .into_diagnostic()?
+                .many()
+                .await
+                .into_diagnostic()?
+                .into_iter()
+                .next(),
+        )
+    }
+
+    /// Queries the permission rules configured for a specific command in a certain guild.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn command_permission_rules(
+        guild_id: Id<GuildMarker>,
+        command: impl Into<String>,
+    ) -> miette::Result<Vec<NightlyCommandPermissionRules>> {
+        CommandPermissionRulesSelectByGuildIdAndCommand::bind(guild_id.to_string(), command.into())
+            .executor()
+            .await
+            .into_diagnostic()?
+            .many()
+            .await
+            .into_diagnostic()
+    }
+
+    /// Queries the permission settings configured for a specific command in a certain guild, if
+    /// any.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn command_permissions(
+        guild_id: Id<GuildMarker>,
+        command: impl Into<String>,
+    ) -> miette::Result<Option<NightlyCommandPermissions>> {
+        Ok(
+            CommandPermissionsSelectByGuildIdAndCommand::bind(guild_id.to_string(), command.into())
+                .executor()
+                .await
+                .into_diagnostic()?
+                .many()
+                .await
+                .into_diagnostic()?
+                .into_iter()
+                .next(),
+        )
+    }
+
     /// Queries whether a specific plugin is enabled for a certain guild.
     #[allow(clippy::missing_errors_doc)]
     pub async fn plugin_enabled(
@@ -51,4 +129,223 @@ impl ConfigurationProvider {
             .await
             .into_diagnostic()
     }
+
+    /// Queries the channel messages reported by members are sent to in a certain guild, if any.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn reports_channel(
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Option<Id<ChannelMarker>>> {
+        let configuration = UtilitiesPluginConfigurationSelectByGuildId::bind(guild_id.to_string())
+            .executor()
+            .await
+            .into_diagnostic()?
+            .many()
+            .await
+            .into_diagnostic()?
+            .into_iter()
+            .next();
+
+        configuration
+            .as_ref()
+            .and_then(|configuration| configuration.reports_channel())
+            .map(|channel_id| channel_id.parse().into_diagnostic())
+            .transpose()
+    }
+
+    /// Stores the parts of the evaluated configuration of a certain guild that are queried by the
+    /// bot, replacing those previously stored for the guild, and announces the change to the other
+    /// services.
+    ///
+    /// This is to be called whenever the configuration of a guild is evaluated after it has been
+    /// changed. Overrides are written before those no longer configured are removed, such that the
+    /// stored configuration is never missing overrides that are still configured.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn store(
+        guild_id: Id<GuildMarker>,
+        configuration: &Configuration,
+        transport: &Transport,
+    ) -> miette::Result<()> {
+        Self::store_cooldowns(guild_id, configuration).await?;
+        Self::store_permissions(guild_id, configuration).await?;
+        Self::store_utilities_plugin(guild_id, configuration).await?;
+
+        transport
+            .publish_event(
+                Channel::InternalEvent,
+                &InternalEvent::ConfigurationChanged(ConfigurationChanged { guild_id }),
+            )
+            .await
+    }
+
+    async fn store_cooldowns(
+        guild_id: Id<GuildMarker>,
+        configuration: &Configuration,
+    ) -> miette::Result<()> {
+        let cooldowns = configuration.cooldowns.iter().flatten().collect::<Vec<_>>();
+
+        if !cooldowns.is_empty() {
+            let rows = cooldowns
+                .iter()
+                .map(|(command, cooldown)| {
+                    json!({
+                        "guild_id": guild_id.to_string(),
+                        "command": command,
+                        "bucket": cooldown.bucket.name(),
+                        "seconds": cooldown.seconds,
+                    })
+                })
+                .collect();
+
+            CommandCooldownUpsertMany::bind(Value::Array(rows).to_string())
+                .executor()
+                .await
+                .into_diagnostic()?
+                .execute()
+                .await
+                .into_diagnostic()?;
+        }
+
+        CommandCooldownDeleteByGuildIdExceptCommands::bind(
+            guild_id.to_string(),
+            cooldowns
+                .into_iter()
+                .map(|(command, _)| command.clone())
+                .collect(),
+        )
+        .executor()
+        .await
+        .into_diagnostic()?
+        .execute()
+        .await
+        .into_diagnostic()?;
+
+        Ok(())
+    }
+
+    async fn store_permissions(
+        guild_id: Id<GuildMarker>,
+        configuration: &Configuration,
+    ) -> miette::Result<()> {
+        let permissions = configuration
+            .permissions
+            .iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        let rules = permissions
+            .iter()
+            .flat_map(|(command, command_permissions)| {
+                command_permissions
+                    .rules
+                    .iter()
+                    .map(move |rule| (*command, rule))
+            })
+            .collect::<Vec<_>>();
+
+        if !permissions.is_empty() {
+            let rows = permissions
+                .iter()
+                .map(|(command, command_permissions)| {
+                    json!({
+                        "guild_id": guild_id.to_string(),
+                        "command": command,
+                        "replace_required_permissions": command_permissions.replace_required_permissions,
+                    })
+                })
+                .collect();
+
+            CommandPermissionsUpsertMany::bind(Value::Array(rows).to_string())
+                .executor()
+                .await
+                .into_diagnostic()?
+                .execute()
+                .await
+                .into_diagnostic()?;
+        }
+
+        if !rules.is_empty() {
+            let rows = rules
+                .iter()
+                .map(|(command, rule)| {
+                    json!({
+                        "guild_id": guild_id.to_string(),
+                        "command": command,
+                        "kind": rule.kind.name(),
+                        "target_id": rule.id,
+                        "allow": rule.allow,
+                    })
+                })
+                .collect();
+
+            CommandPermissionRulesUpsertMany::bind(Value::Array(rows).to_string())
+                .executor()
+                .await
+                .into_diagnostic()?
+                .execute()
+                .await
+                .into_diagnostic()?;
+        }
+
+        CommandPermissionRulesDeleteByGuildIdExceptRules::bind(
+            guild_id.to_string(),
+            rules
+                .into_iter()
+                .map(|(command, rule)| format!("{command}:{}:{}", rule.kind.name(), rule.id))
+                .collect(),
+        )
+        .executor()
+        .await
+        .into_diagnostic()?
+        .execute()
+        .await
+        .into_diagnostic()?;
+
+        CommandPermissionsDeleteByGuildIdExceptCommands::bind(
+            guild_id.to_string(),
+            permissions
+                .into_iter()
+                .map(|(command, _)| command.clone())
+                .collect(),
+        )
+        .executor()
+        .await
+        .into_diagnostic()?
+        .execute()
+        .await
+        .into_diagnostic()?;
+
+        Ok(())
+    }
+
+    async fn store_utilities_plugin(
+        guild_id: Id<GuildMarker>,
+        configuration: &Configuration,
+    ) -> miette::Result<()> {
+        let utilities = configuration
+            .plugins
+            .as_ref()
+            .and_then(|plugins| plugins.utilities.as_ref());
+
+        if let Some(utilities) = utilities {
+            UtilitiesPluginConfigurationUpsert::bind(
+                guild_id.to_string(),
+                utilities.reports_channel.clone(),
+            )
+            .executor()
+            .await
+            .into_diagnostic()?
+            .execute()
+            .await
+            .into_diagnostic()?;
+        } else {
+            UtilitiesPluginConfigurationDeleteByGuildId::bind(guild_id.to_string())
+                .executor()
+                .await
+                .into_diagnostic()?
+                .execute()
+                .await
+                .into_diagnostic()?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,124 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Inspect Command
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_kafka_utils::deadletter::DeadLetter;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use owo_colors::OwoColorize;
+use rdkafka::Message;
+use rdkafka::message::BorrowedMessage;
+
+use crate::commands::POLL_TIMEOUT;
+use crate::commands::dead_letter_consumer;
+
+/// The number of characters of a payload to print, unless the full payload is requested.
+const PAYLOAD_PREVIEW_LENGTH: usize = 256;
+
+/// Inspect the payloads on the dead-letter topic.
+///
+/// Offsets are never committed by this command, so all dead letters still retained by the topic
+/// are listed every time.
+#[allow(clippy::module_name_repetitions)]
+pub fn inspect_command(matches: &ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let full_payload = matches.get_flag("full-payload");
+    let limit = matches.get_one::<usize>("limit").copied();
+
+    let consumer =
+        dead_letter_consumer("com.github.teamhartex.hartex.deadletter.inspect.consumer")?;
+
+    let mut count = 0;
+    while limit.is_none_or(|limit| count < limit) {
+        let Some(result) = consumer.poll(POLL_TIMEOUT) else {
+            break;
+        };
+
+        print_dead_letter(&result.into_diagnostic()?, full_payload);
+        count += 1;
+    }
+
+    println!();
+    println!("{count} dead letter(s) listed");
+
+    Ok(())
+}
+
+/// Print a payload on the dead-letter topic.
+fn print_dead_letter(message: &BorrowedMessage<'_>, full_payload: bool) {
+    println!();
+    println!(
+        "{}{} / {}",
+        "Partition / Offset: ".bold(),
+        message.partition().bright_cyan(),
+        message.offset().bright_cyan()
+    );
+
+    let Some(dead_letter) = message.headers().and_then(DeadLetter::from_headers) else {
+        println!("{}", "Missing dead-letter headers".red());
+        return;
+    };
+
+    println!(
+        "{}{}",
+        "Consumer: ".bold(),
+        dead_letter.consumer.bright_cyan()
+    );
+    println!(
+        "{}{}",
+        "Origin Topic: ".bold(),
+        dead_letter.origin_topic.bright_cyan()
+    );
+    match dead_letter.shard {
+        Some(shard) => println!("{}{}", "Shard: ".bold(), shard.bright_cyan()),
+        None => println!("{}{}", "Shard: ".bold(), "Unknown".truecolor(107, 107, 107)),
+    }
+    match dead_letter.event_type {
+        Some(event_type) => println!("{}{}", "Event Type: ".bold(), event_type.bright_cyan()),
+        None => println!(
+            "{}{}",
+            "Event Type: ".bold(),
+            "Unknown".truecolor(107, 107, 107)
+        ),
+    }
+    println!("{}{}", "Error: ".bold(), dead_letter.error.bright_red());
+
+    let payload = String::from_utf8_lossy(message.payload().unwrap_or_default());
+    if full_payload || payload.chars().count() <= PAYLOAD_PREVIEW_LENGTH {
+        println!("{}{payload}", "Payload: ".bold());
+    } else {
+        let preview = payload
+            .chars()
+            .take(PAYLOAD_PREVIEW_LENGTH)
+            .collect::<String>();
+        println!(
+            "{}{preview}{}",
+            "Payload: ".bold(),
+            "... (truncated, pass --full-payload to print in full)".truecolor(107, 107, 107)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+/// # Commands
+///
+/// - inspect
+/// - replay
+pub mod inspect;
+pub mod replay;
+
+use std::env;
+use std::time::Duration;
+
+use hartex_kafka_utils::traits::ClientConfigUtils;
+use miette::IntoDiagnostic;
+use rdkafka::ClientConfig;
+use rdkafka::consumer::BaseConsumer;
+use rdkafka::consumer::Consumer;
+
+/// How long to wait for another dead letter before assuming the end of the topic has been reached.
+pub const POLL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Obtain the configured Kafka bootstrap servers.
+pub fn bootstrap_servers() -> miette::Result<Vec<String>> {
+    Ok(env::var("KAFKA_BOOTSTRAP_SERVERS")
+        .into_diagnostic()?
+        .split(';')
+        .map(String::from)
+        .collect())
+}
+
+/// Create a consumer reading the dead-letter topic from its earliest uncommitted payload.
+pub fn dead_letter_consumer(group_id: &str) -> miette::Result<BaseConsumer> {
+    let topic =
+        env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_DEAD_LETTER").into_diagnostic()?;
+
+    let consumer = ClientConfig::new()
+        .bootstrap_servers(bootstrap_servers()?.into_iter())
+        .group_id(group_id)
+        .auto_offset_reset("earliest")
+        .enable_auto_commit(false)
+        .create::<BaseConsumer>()
+        .into_diagnostic()?;
+
+    consumer.subscribe(&[&topic]).into_diagnostic()?;
+
+    Ok(consumer)
+}
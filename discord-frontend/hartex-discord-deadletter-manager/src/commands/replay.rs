@@ -0,0 +1,108 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Replay Command
+
+use std::time::Duration;
+
+use hartex_discord_core::dotenvy;
+use hartex_kafka_utils::deadletter::DeadLetter;
+use hartex_kafka_utils::traits::ClientConfigUtils;
+use hartex_kafka_utils::types::CompressionType;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use rdkafka::ClientConfig;
+use rdkafka::Message;
+use rdkafka::consumer::CommitMode;
+use rdkafka::consumer::Consumer;
+use rdkafka::producer::FutureProducer;
+use rdkafka::producer::FutureRecord;
+use rdkafka::util::Timeout;
+
+use crate::commands::POLL_TIMEOUT;
+use crate::commands::bootstrap_servers;
+use crate::commands::dead_letter_consumer;
+
+/// Replay the payloads on the dead-letter topic that have not been replayed yet.
+///
+/// Each payload is republished to the topic it was originally published to, with its original key,
+/// such that it is consumed again by the consumer that failed to process it. Offsets are committed
+/// as payloads are replayed, so a payload is only ever replayed once.
+#[allow(clippy::module_name_repetitions)]
+pub async fn replay_command() -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let producer = ClientConfig::new()
+        .bootstrap_servers(bootstrap_servers()?.into_iter())
+        .compression_type(CompressionType::Lz4)
+        .delivery_timeout_ms(30000)
+        .create::<FutureProducer>()
+        .into_diagnostic()?;
+    let consumer = dead_letter_consumer("com.github.teamhartex.hartex.deadletter.replay.consumer")?;
+
+    let mut replayed = 0;
+    while let Some(result) = consumer.poll(POLL_TIMEOUT) {
+        let message = result.into_diagnostic()?;
+
+        let Some(dead_letter) = message.headers().and_then(DeadLetter::from_headers) else {
+            log::warn!(
+                "skipping payload without dead-letter headers at partition {} offset {}",
+                message.partition(),
+                message.offset()
+            );
+            consumer
+                .commit_message(&message, CommitMode::Sync)
+                .into_diagnostic()?;
+
+            continue;
+        };
+
+        let mut record = FutureRecord::<[u8], [u8]>::to(&dead_letter.origin_topic)
+            .payload(message.payload().unwrap_or_default());
+        if let Some(key) = message.key() {
+            record = record.key(key);
+        }
+
+        producer
+            .send(record, Timeout::After(Duration::from_secs(0)))
+            .await
+            .map_err(|(error, _)| error)
+            .into_diagnostic()?;
+        consumer
+            .commit_message(&message, CommitMode::Sync)
+            .into_diagnostic()?;
+
+        log::info!(
+            "replayed {} payload from partition {} offset {} to {}",
+            dead_letter.event_type.as_deref().unwrap_or("UNKNOWN"),
+            message.partition(),
+            message.offset(),
+            dead_letter.origin_topic
+        );
+        replayed += 1;
+    }
+
+    println!("{replayed} dead letter(s) replayed");
+
+    Ok(())
+}
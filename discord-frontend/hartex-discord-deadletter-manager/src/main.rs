@@ -0,0 +1,76 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Dead-Letter Manager Executable
+//!
+//! This is the executable for the dead-letter manager, which inspects and replays gateway payloads
+//! that could not be processed and were sent to the dead-letter topic.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use clap::Arg;
+use clap::ArgAction;
+use clap::Command;
+use clap::value_parser;
+use hartex_discord_core::tokio;
+
+mod cmdline;
+mod commands;
+
+/// Manager entry point.
+#[tokio::main(flavor = "multi_thread")]
+pub async fn main() -> miette::Result<()> {
+    hartex_log::initialize();
+
+    let command = Command::new("dlmgr")
+        .subcommand(
+            Command::new("inspect")
+                .about("Lists the payloads on the dead-letter topic.")
+                .arg(
+                    Arg::new("full-payload")
+                        .long("full-payload")
+                        .short('f')
+                        .num_args(0)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("limit")
+                        .long("limit")
+                        .short('l')
+                        .action(ArgAction::Set)
+                        .value_parser(value_parser!(usize)),
+                ),
+        )
+        .subcommand(
+            Command::new("replay").about(
+                "Republishes the payloads on the dead-letter topic that have not been replayed yet to their original topics.",
+            ),
+        );
+
+    let matches = command.get_matches();
+
+    cmdline::handle(matches).await?;
+
+    Ok(())
+}
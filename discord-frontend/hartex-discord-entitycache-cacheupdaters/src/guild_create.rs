@@ -27,9 +27,11 @@
 use hartex_discord_core::discord::model::gateway::payload::incoming::GuildCreate;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
 use hartex_discord_entitycache_entities::emoji::EmojiEntity;
 use hartex_discord_entitycache_entities::guild::GuildEntity;
 use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_repositories::channel::CachedChannelRepository;
 use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
 use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
 use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
@@ -46,18 +48,26 @@ impl CacheUpdater for GuildCreate {
 
         CachedGuildRepository.upsert(entity).await?;
 
-        for role in &guild.roles {
-            CachedRoleRepository
-                .upsert(RoleEntity::from((guild.id, role.clone())))
-                .await?;
-        }
+        let roles = guild
+            .roles
+            .iter()
+            .map(|role| RoleEntity::from((guild.id, role.clone())))
+            .collect();
+        CachedRoleRepository.upsert_many(roles).await?;
 
-        for emoji in &guild.emojis {
-            CachedEmojiRepository
-                .upsert(EmojiEntity::from((guild.id, emoji.clone())))
-                .await?;
-        }
+        let emojis = guild
+            .emojis
+            .iter()
+            .map(|emoji| EmojiEntity::from((guild.id, emoji.clone())))
+            .collect();
+        CachedEmojiRepository.upsert_many(emojis).await?;
 
-        Ok(())
+        let channels = guild
+            .channels
+            .iter()
+            .chain(&guild.threads)
+            .map(|channel| ChannelEntity::from((guild.id, channel.clone())))
+            .collect();
+        CachedChannelRepository.upsert_many(channels).await
     }
 }
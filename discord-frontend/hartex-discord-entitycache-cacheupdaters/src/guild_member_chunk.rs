@@ -32,14 +32,18 @@ use crate::CacheUpdater;
 
 impl CacheUpdater for MemberChunk {
     async fn update(&self) -> CacheResult<()> {
-        for member in &self.members {
-            let member_entity = MemberEntity::from((self.guild_id, member.user.id, member.clone()));
-            let user_entity = UserEntity::from(member.user.clone());
+        let members = self
+            .members
+            .iter()
+            .map(|member| MemberEntity::from((self.guild_id, member.user.id, member.clone())))
+            .collect();
+        let users = self
+            .members
+            .iter()
+            .map(|member| UserEntity::from(member.user.clone()))
+            .collect();
 
-            CachedMemberRepository.upsert(member_entity).await?;
-            CachedUserRepository.upsert(user_entity).await?;
-        }
-
-        Ok(())
+        CachedMemberRepository.upsert_many(members).await?;
+        CachedUserRepository.upsert_many(users).await
     }
 }
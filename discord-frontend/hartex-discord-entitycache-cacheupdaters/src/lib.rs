@@ -31,8 +31,25 @@
 
 use hartex_discord_entitycache_core::error::CacheResult;
 
+pub mod channel_create;
+pub mod channel_delete;
+pub mod channel_update;
 pub mod guild_create;
+pub mod guild_delete;
+pub mod guild_emojis_update;
 pub mod guild_member_chunk;
+pub mod guild_update;
+pub mod member_add;
+pub mod member_remove;
+pub mod member_update;
+pub mod role_create;
+pub mod role_delete;
+pub mod role_update;
+pub mod thread_create;
+pub mod thread_delete;
+pub mod thread_list_sync;
+pub mod thread_update;
+pub mod user_update;
 
 /// A trait for all cache updaters to implement.
 pub trait CacheUpdater {
@@ -0,0 +1,55 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Member Update Cache Updater
+//!
+//! An implementation of a cache updater for the member update event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::MemberUpdate;
+use hartex_discord_core::discord::model::guild::MemberFlags;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::member::MemberEntity;
+use hartex_discord_entitycache_entities::user::UserEntity;
+use hartex_discord_entitycache_repositories::member::CachedMemberRepository;
+use hartex_discord_entitycache_repositories::user::CachedUserRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for MemberUpdate {
+    async fn update(&self) -> CacheResult<()> {
+        // member updates carry every field that is cached, such that members that were not cached
+        // before are inserted instead of the update being lost
+        let member_entity = MemberEntity {
+            flags: self.flags.unwrap_or_else(MemberFlags::empty),
+            guild_id: self.guild_id,
+            joined_at: self.joined_at,
+            nick: self.nick.clone(),
+            roles: self.roles.clone(),
+            user_id: self.user.id,
+        };
+        let user_entity = UserEntity::from(self.user.clone());
+
+        CachedMemberRepository.upsert(member_entity).await?;
+        CachedUserRepository.upsert(user_entity).await
+    }
+}
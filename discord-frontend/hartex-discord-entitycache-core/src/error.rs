@@ -29,6 +29,7 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 
 use hartex_database_queries::result::Error as DatabaseError;
+use tokio_postgres::Error as PostgresError;
 
 /// A cache error..
 #[allow(clippy::module_name_repetitions)]
@@ -38,6 +39,10 @@ pub enum CacheError {
     Env(VarError),
     /// A postgres error occurred.
     Database(DatabaseError),
+    /// The requested entity is not in the cache.
+    NotFound,
+    /// A postgres error occurred outside of the generated queries.
+    Postgres(PostgresError),
 }
 
 impl Display for CacheError {
@@ -45,6 +50,8 @@ impl Display for CacheError {
         match self {
             Self::Env(error) => writeln!(f, "env error: {error}"),
             Self::Database(error) => writeln!(f, "database error: {error}"),
+            Self::NotFound => writeln!(f, "entity not found"),
+            Self::Postgres(error) => writeln!(f, "postgres error: {error}"),
         }
     }
 }
@@ -57,6 +64,12 @@ impl From<DatabaseError> for CacheError {
     }
 }
 
+impl From<PostgresError> for CacheError {
+    fn from(error: PostgresError) -> Self {
+        Self::Postgres(error)
+    }
+}
+
 impl From<VarError> for CacheError {
     fn from(error: VarError) -> Self {
         Self::Env(error)
@@ -33,13 +33,61 @@ pub trait Entity {
     fn id(&self) -> Self::Id;
 }
 
+/// A page of entities to list from a repository.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Page {
+    /// The maximum number of entities in the page.
+    pub limit: u32,
+    /// The number of entities to skip before the page.
+    pub offset: u32,
+}
+
+impl Page {
+    /// Returns the page at the given zero-based index, with each page holding `size` entities.
+    #[must_use]
+    pub fn nth(index: u32, size: u32) -> Self {
+        Self {
+            limit: size,
+            offset: index.saturating_mul(size),
+        }
+    }
+}
+
 /// A cache repository holding entities.
 pub trait Repository<T: Entity> {
+    /// The key to list entities by.
+    type ListKey;
+
+    /// Deletes an entity from the repository.
+    #[allow(async_fn_in_trait)]
+    async fn delete(&self, entity_id: T::Id) -> CacheResult<()>;
+
+    /// Checks whether an entity is in the repository.
+    #[allow(async_fn_in_trait)]
+    async fn exists(&self, entity_id: T::Id) -> CacheResult<bool>;
+
     /// Retrieves an entity from the repository.
+    ///
+    /// Returns [`CacheError::NotFound`](crate::error::CacheError::NotFound) if the entity is not
+    /// in the repository.
     #[allow(async_fn_in_trait)]
     async fn get(&self, entity_id: T::Id) -> CacheResult<T>;
 
+    /// Retrieves multiple entities from the repository at once.
+    ///
+    /// Entities that are not in the repository are left out of the result.
+    #[allow(async_fn_in_trait)]
+    async fn get_many(&self, entity_ids: &[T::Id]) -> CacheResult<Vec<T>>;
+
+    /// Lists a page of the entities with the given key, ordered by their identifiers.
+    #[allow(async_fn_in_trait)]
+    async fn list_by(&self, key: Self::ListKey, page: Page) -> CacheResult<Vec<T>>;
+
     /// Upserts an entity into the repository.
     #[allow(async_fn_in_trait)]
     async fn upsert(&self, entity: T) -> CacheResult<()>;
+
+    /// Upserts multiple entities into the repository at once.
+    #[allow(async_fn_in_trait)]
+    async fn upsert_many(&self, entities: Vec<T>) -> CacheResult<()>;
 }
@@ -38,4 +38,5 @@ use hartex_discord_entitycache_core::entity;
     overrides = [],
     relates = [],
 )]
+#[derive(Clone)]
 pub struct EmojiEntity;
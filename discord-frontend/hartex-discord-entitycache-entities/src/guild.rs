@@ -53,9 +53,11 @@ use hartex_discord_entitycache_core::entity;
         "VerificationLevel": "twilight_model::guild::VerificationLevel"
     ],
     relates = [
+        multiple "ChannelEntity": via "id" as "guild_id",
         multiple "EmojiEntity": via "id" as "guild_id",
         multiple "MemberEntity": via "id" as "guild_id",
         multiple "RoleEntity": via "id" as "guild_id",
     ],
 )]
+#[derive(Clone)]
 pub struct GuildEntity;
@@ -29,6 +29,7 @@
 #![deny(warnings)]
 #![allow(deprecated)]
 
+pub mod channel;
 pub mod emoji;
 pub mod guild;
 pub mod member;
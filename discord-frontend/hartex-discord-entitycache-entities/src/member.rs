@@ -41,4 +41,5 @@ use hartex_discord_entitycache_core::entity;
         unique "UserEntity": via "user_id" as "id",
     ],
 )]
+#[derive(Clone)]
 pub struct MemberEntity;
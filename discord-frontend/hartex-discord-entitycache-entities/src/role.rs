@@ -28,15 +28,26 @@ use hartex_discord_entitycache_core::entity;
     from = "twilight_model::guild::Role",
     assume = ["NightlyCachedRoles"],
     id = ["guild_id", "id"],
-    include = ["color", "flags", "hoist", "icon", "managed", "mentionable", "position"],
+    include = [
+        "color",
+        "flags",
+        "hoist",
+        "icon",
+        "managed",
+        "mentionable",
+        "permissions",
+        "position"
+    ],
     extra = [
         "guild_id": "Id<GuildMarker>",
     ],
     overrides = [
+        "Permissions": "twilight_model::guild::Permissions",
         "RoleFlags": "twilight_model::guild::RoleFlags"
     ],
     relates = [
         unique "GuildEntity": via "guild_id" as "id",
     ],
 )]
+#[derive(Clone)]
 pub struct RoleEntity;
@@ -39,4 +39,5 @@ use hartex_discord_entitycache_core::entity;
     overrides = [],
     relates = [],
 )]
+#[derive(Clone)]
 pub struct UserEntity;
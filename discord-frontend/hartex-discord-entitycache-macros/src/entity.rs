@@ -54,7 +54,8 @@ const PRELUDE_AND_PRIMITIVES: [&str; 21] = [
 ];
 
 /// Valid entities for inter-entity relationships.
-const VALID_ENTITIES: [(&str, &str); 5] = [
+const VALID_ENTITIES: [(&str, &str); 6] = [
+    ("ChannelEntity", "crate::channel::ChannelEntity"),
     ("EmojiEntity", "crate::emoji::EmojiEntity"),
     ("GuildEntity", "crate::guild::GuildEntity"),
     ("MemberEntity", "crate::member::MemberEntity"),
@@ -565,8 +566,18 @@ fn make_field_decl_and_assignments(
         );
     }
 
+    // Field name special case for nested structures that are stored as JSON
+    if field_name == "permission_overwrites" || field_name == "thread_metadata" {
+        return (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name().map(|json| serde_json::from_str(json).unwrap())},
+        );
+    }
+
     // Field type special case
-    if field_type.is_enum("DefaultMessageNotificationLevel")
+    if field_type.is_enum("ChannelType")
+        || field_type.is_enum("DefaultMessageNotificationLevel")
         || field_type.is_enum("ExplicitContentFilter")
         || field_type.is_enum("MfaLevel")
         || field_type.is_enum("PremiumTier")
@@ -583,12 +594,24 @@ fn make_field_decl_and_assignments(
             quote! {#field_name: model.#field_name},
             quote! {#field_name: std::str::FromStr::from_str(model.#field_name()).unwrap()},
         )
+    } else if field_type.is_option_of("Id") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name().map(|str| std::str::FromStr::from_str(str).unwrap())},
+        )
     } else if field_type.is("MemberFlags") {
         (
             quote! {pub #field_name: #field_type},
             quote! {#field_name: model.#field_name},
             quote! {#field_name: twilight_model::guild::MemberFlags::from_bits(model.#field_name() as u64).unwrap()},
         )
+    } else if field_type.is("Permissions") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: twilight_model::guild::Permissions::from_bits_truncate(model.#field_name() as u64)},
+        )
     } else if field_type.is("RoleFlags") {
         (
             quote! {pub #field_name: #field_type},
@@ -631,6 +654,12 @@ fn make_field_decl_and_assignments(
             quote! {#field_name: model.#field_name},
             quote! {#field_name: model.#field_name().to_string()},
         )
+    } else if field_type.is_option_of("u16") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name().map(|i| i as u16)},
+        )
     } else if field_type.is_option_of("u64") {
         (
             quote! {pub #field_name: #field_type},
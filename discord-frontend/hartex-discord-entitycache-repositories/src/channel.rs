@@ -0,0 +1,280 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_database_queries::queries::discord_frontend::cached_channel_delete_by_guild_id::CachedChannelDeleteByGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_channel_delete_by_id::CachedChannelDeleteById;
+use hartex_database_queries::queries::discord_frontend::cached_channel_delete_by_parent_id::CachedChannelDeleteByParentId;
+use hartex_database_queries::queries::discord_frontend::cached_channel_exists_by_id::CachedChannelExistsById;
+use hartex_database_queries::queries::discord_frontend::cached_channel_select_by_guild_id_paginated::CachedChannelSelectByGuildIdPaginated;
+use hartex_database_queries::queries::discord_frontend::cached_channel_select_by_id::CachedChannelSelectById;
+use hartex_database_queries::queries::discord_frontend::cached_channel_select_by_ids::CachedChannelSelectByIds;
+use hartex_database_queries::queries::discord_frontend::cached_channel_upsert::CachedChannelUpsert;
+use hartex_database_queries::queries::discord_frontend::cached_channel_upsert_many::CachedChannelUpsertMany;
+use hartex_database_queries::queries::discord_frontend::cached_thread_delete_stale_by_guild_id::CachedThreadDeleteStaleByGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_thread_delete_stale_by_parent_ids::CachedThreadDeleteStaleByParentIds;
+use hartex_discord_core::discord::model::channel::ChannelType;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_entitycache_core::error::CacheError;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Page;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::memory;
+
+/// Repository for channel entities.
+pub struct CachedChannelRepository;
+
+impl CachedChannelRepository {
+    /// Deletes every channel and thread of a guild from the repository.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        CachedChannelDeleteByGuildId::bind(guild_id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::CHANNELS.remove_where(|_, entity| entity.guild_id == guild_id);
+
+        Ok(())
+    }
+
+    /// Deletes every channel or thread with the given parent from the repository.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn delete_by_parent_id(&self, parent_id: Id<ChannelMarker>) -> CacheResult<()> {
+        CachedChannelDeleteByParentId::bind(Some(parent_id.to_string()))
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::CHANNELS.remove_where(|_, entity| entity.parent_id == Some(parent_id));
+
+        Ok(())
+    }
+
+    /// Deletes every thread of a guild not in the given list of active threads.
+    ///
+    /// Only threads under the given parent channels are considered, or every thread in the guild
+    /// when no parent channel is given.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn delete_stale_threads(
+        &self,
+        guild_id: Id<GuildMarker>,
+        parent_ids: &[Id<ChannelMarker>],
+        active_ids: &[Id<ChannelMarker>],
+    ) -> CacheResult<()> {
+        let ids = active_ids
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        if parent_ids.is_empty() {
+            CachedThreadDeleteStaleByGuildId::bind(guild_id.to_string(), ids)
+                .executor()
+                .await?
+                .execute()
+                .await?;
+        } else {
+            CachedThreadDeleteStaleByParentIds::bind(
+                parent_ids.iter().map(ToString::to_string).collect(),
+                ids,
+            )
+            .executor()
+            .await?
+            .execute()
+            .await?;
+        }
+
+        memory::CHANNELS.remove_where(|id, entity| {
+            entity.guild_id == guild_id
+                && entity.kind.is_thread()
+                && !active_ids.contains(id)
+                && (parent_ids.is_empty()
+                    || entity
+                        .parent_id
+                        .is_some_and(|parent_id| parent_ids.contains(&parent_id)))
+        });
+
+        Ok(())
+    }
+}
+
+impl Repository<ChannelEntity> for CachedChannelRepository {
+    type ListKey = Id<GuildMarker>;
+
+    async fn delete(&self, id: <ChannelEntity as Entity>::Id) -> CacheResult<()> {
+        CachedChannelDeleteById::bind(id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::CHANNELS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <ChannelEntity as Entity>::Id) -> CacheResult<bool> {
+        if memory::CHANNELS.contains(&id) {
+            return Ok(true);
+        }
+
+        let exists = CachedChannelExistsById::bind(id.to_string())
+            .executor()
+            .await?
+            .one()
+            .await?;
+
+        Ok(exists)
+    }
+
+    async fn get(&self, id: <ChannelEntity as Entity>::Id) -> CacheResult<ChannelEntity> {
+        if let Some(entity) = memory::CHANNELS.get(&id) {
+            return Ok(entity);
+        }
+
+        let data = CachedChannelSelectById::bind(id.to_string())
+            .executor()
+            .await?
+            .many()
+            .await?;
+
+        let entity = data
+            .into_iter()
+            .next()
+            .map(ChannelEntity::from)
+            .ok_or(CacheError::NotFound)?;
+        memory::CHANNELS.insert(id, entity.clone());
+
+        Ok(entity)
+    }
+
+    async fn get_many(
+        &self,
+        ids: &[<ChannelEntity as Entity>::Id],
+    ) -> CacheResult<Vec<ChannelEntity>> {
+        let data = CachedChannelSelectByIds::bind(ids.iter().map(ToString::to_string).collect())
+            .executor()
+            .await?
+            .many()
+            .await?;
+
+        Ok(data.into_iter().map(ChannelEntity::from).collect())
+    }
+
+    async fn list_by(
+        &self,
+        guild_id: Self::ListKey,
+        page: Page,
+    ) -> CacheResult<Vec<ChannelEntity>> {
+        let data = CachedChannelSelectByGuildIdPaginated::bind(
+            guild_id.to_string(),
+            i64::from(page.limit),
+            i64::from(page.offset),
+        )
+        .executor()
+        .await?
+        .many()
+        .await?;
+
+        Ok(data.into_iter().map(ChannelEntity::from).collect())
+    }
+
+    async fn upsert(&self, entity: ChannelEntity) -> CacheResult<()> {
+        let id = entity.id();
+
+        CachedChannelUpsert::bind(
+            entity.guild_id.to_string(),
+            entity.id.to_string(),
+            i16::from(<ChannelType as Into<u8>>::into(entity.kind)),
+            entity.name,
+            entity.nsfw,
+            entity.parent_id.map(|id| id.to_string()),
+            entity
+                .permission_overwrites
+                .map(|overwrites| serde_json::to_string(&overwrites).unwrap()),
+            entity.position,
+            entity.rate_limit_per_user.map(i32::from),
+            entity
+                .thread_metadata
+                .map(|metadata| serde_json::to_string(&metadata).unwrap()),
+            entity.topic,
+        )
+        .executor()
+        .await?
+        .execute()
+        .await?;
+
+        memory::CHANNELS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<ChannelEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let ids = entities.iter().map(Entity::id).collect::<Vec<_>>();
+        let channels = entities
+            .into_iter()
+            .map(|entity| {
+                json!({
+                    "guild_id": entity.guild_id.to_string(),
+                    "id": entity.id.to_string(),
+                    "kind": u8::from(entity.kind),
+                    "name": entity.name,
+                    "nsfw": entity.nsfw,
+                    "parent_id": entity.parent_id.map(|id| id.to_string()),
+                    "permission_overwrites": entity
+                        .permission_overwrites
+                        .map(|overwrites| serde_json::to_string(&overwrites).unwrap()),
+                    "position": entity.position,
+                    "rate_limit_per_user": entity.rate_limit_per_user,
+                    "thread_metadata": entity
+                        .thread_metadata
+                        .map(|metadata| serde_json::to_string(&metadata).unwrap()),
+                    "topic": entity.topic,
+                })
+            })
+            .collect();
+
+        CachedChannelUpsertMany::bind(Value::Array(channels).to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        for id in ids {
+            memory::CHANNELS.remove(&id);
+        }
+
+        Ok(())
+    }
+}
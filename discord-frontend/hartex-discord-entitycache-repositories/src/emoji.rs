@@ -20,28 +20,180 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use hartex_database_queries::queries::discord_frontend::cached_emoji_delete_by_guild_id::CachedEmojiDeleteByGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_emoji_delete_by_id::CachedEmojiDeleteById;
+use hartex_database_queries::queries::discord_frontend::cached_emoji_exists_by_id::CachedEmojiExistsById;
+use hartex_database_queries::queries::discord_frontend::cached_emoji_select_by_guild_id_paginated::CachedEmojiSelectByGuildIdPaginated;
 use hartex_database_queries::queries::discord_frontend::cached_emoji_select_by_id::CachedEmojiSelectById;
+use hartex_database_queries::queries::discord_frontend::cached_emoji_select_by_ids::CachedEmojiSelectByIds;
 use hartex_database_queries::queries::discord_frontend::cached_emoji_upsert::CachedEmojiUpsert;
+use hartex_database_queries::queries::discord_frontend::cached_emoji_upsert_many::CachedEmojiUpsertMany;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_entitycache_core::error::CacheError;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Page;
 use hartex_discord_entitycache_core::traits::Repository;
 use hartex_discord_entitycache_entities::emoji::EmojiEntity;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::memory;
+use crate::postgres;
 
 /// Repository for emoji entities.
 pub struct CachedEmojiRepository;
 
+impl CachedEmojiRepository {
+    /// Deletes every emoji of a guild from the repository.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        CachedEmojiDeleteByGuildId::bind(guild_id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::EMOJIS.remove_where(|_, entity| entity.guild_id == guild_id);
+
+        Ok(())
+    }
+
+    /// Replaces every emoji of a guild in the repository with the given ones, in a single
+    /// transaction.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn replace_by_guild_id(
+        &self,
+        guild_id: Id<GuildMarker>,
+        entities: Vec<EmojiEntity>,
+    ) -> CacheResult<()> {
+        let emojis = entities
+            .into_iter()
+            .map(|entity| {
+                json!({
+                    "animated": entity.animated,
+                    "name": entity.name,
+                    "id": entity.id.to_string(),
+                    "guild_id": entity.guild_id.to_string(),
+                    "managed": entity.managed,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut client = postgres::connect().await?;
+        let transaction = client.transaction().await?;
+        transaction
+            .execute(
+                r#"DELETE FROM "DiscordFrontend"."Nightly"."CachedEmojis" WHERE "guild_id" = $1"#,
+                &[&guild_id.to_string()],
+            )
+            .await?;
+
+        if !emojis.is_empty() {
+            transaction
+                .execute(
+                    r#"INSERT INTO "DiscordFrontend"."Nightly"."CachedEmojis" ("animated", "name", "id", "guild_id", "managed")
+SELECT
+    "animated", "name", "id", "guild_id", "managed"
+FROM
+    JSONB_TO_RECORDSET(CAST($1::TEXT AS JSONB)) AS "Emojis" ("animated" BOOLEAN, "name" TEXT, "id" TEXT, "guild_id" TEXT, "managed" BOOLEAN)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "guild_id" = "excluded"."guild_id",
+        "animated" = "excluded"."animated",
+        "name" = "excluded"."name",
+        "managed" = "excluded"."managed""#,
+                    &[&Value::Array(emojis).to_string()],
+                )
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        memory::EMOJIS.remove_where(|_, entity| entity.guild_id == guild_id);
+
+        Ok(())
+    }
+}
+
 impl Repository<EmojiEntity> for CachedEmojiRepository {
+    type ListKey = Id<GuildMarker>;
+
+    async fn delete(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<()> {
+        CachedEmojiDeleteById::bind(id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::EMOJIS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<bool> {
+        if memory::EMOJIS.contains(&id) {
+            return Ok(true);
+        }
+
+        let exists = CachedEmojiExistsById::bind(id.to_string())
+            .executor()
+            .await?
+            .one()
+            .await?;
+
+        Ok(exists)
+    }
+
     async fn get(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<EmojiEntity> {
+        if let Some(entity) = memory::EMOJIS.get(&id) {
+            return Ok(entity);
+        }
+
         let data = CachedEmojiSelectById::bind(id.to_string())
             .executor()
             .await?
-            .one()
+            .many()
+            .await?;
+
+        let entity = data
+            .into_iter()
+            .next()
+            .map(EmojiEntity::from)
+            .ok_or(CacheError::NotFound)?;
+        memory::EMOJIS.insert(id, entity.clone());
+
+        Ok(entity)
+    }
+
+    async fn get_many(&self, ids: &[<EmojiEntity as Entity>::Id]) -> CacheResult<Vec<EmojiEntity>> {
+        let data = CachedEmojiSelectByIds::bind(ids.iter().map(ToString::to_string).collect())
+            .executor()
+            .await?
+            .many()
             .await?;
 
-        Ok(EmojiEntity::from(data))
+        Ok(data.into_iter().map(EmojiEntity::from).collect())
+    }
+
+    async fn list_by(&self, guild_id: Self::ListKey, page: Page) -> CacheResult<Vec<EmojiEntity>> {
+        let data = CachedEmojiSelectByGuildIdPaginated::bind(
+            guild_id.to_string(),
+            i64::from(page.limit),
+            i64::from(page.offset),
+        )
+        .executor()
+        .await?
+        .many()
+        .await?;
+
+        Ok(data.into_iter().map(EmojiEntity::from).collect())
     }
 
     async fn upsert(&self, entity: EmojiEntity) -> CacheResult<()> {
+        let id = entity.id();
+
         CachedEmojiUpsert::bind(
             entity.animated,
             entity.id.to_string(),
@@ -54,6 +206,40 @@ impl Repository<EmojiEntity> for CachedEmojiRepository {
         .execute()
         .await?;
 
+        memory::EMOJIS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<EmojiEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let ids = entities.iter().map(Entity::id).collect::<Vec<_>>();
+        let emojis = entities
+            .into_iter()
+            .map(|entity| {
+                json!({
+                    "animated": entity.animated,
+                    "name": entity.name,
+                    "id": entity.id.to_string(),
+                    "guild_id": entity.guild_id.to_string(),
+                    "managed": entity.managed,
+                })
+            })
+            .collect();
+
+        CachedEmojiUpsertMany::bind(Value::Array(emojis).to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        for id in ids {
+            memory::EMOJIS.remove(&id);
+        }
+
         Ok(())
     }
 }
@@ -24,37 +24,155 @@
 
 use std::borrow::Cow;
 
+use hartex_database_queries::queries::discord_frontend::cached_guild_delete_by_id::CachedGuildDeleteById;
+use hartex_database_queries::queries::discord_frontend::cached_guild_exists_by_id::CachedGuildExistsById;
 use hartex_database_queries::queries::discord_frontend::cached_guild_select_by_id::CachedGuildSelectById;
+use hartex_database_queries::queries::discord_frontend::cached_guild_select_by_ids::CachedGuildSelectByIds;
+use hartex_database_queries::queries::discord_frontend::cached_guild_select_paginated::CachedGuildSelectPaginated;
+use hartex_database_queries::queries::discord_frontend::cached_guild_update_by_id::CachedGuildUpdateById;
 use hartex_database_queries::queries::discord_frontend::cached_guild_upsert::CachedGuildUpsert;
+use hartex_database_queries::queries::discord_frontend::cached_guild_upsert_many::CachedGuildUpsertMany;
 use hartex_discord_core::discord::model::guild::DefaultMessageNotificationLevel;
 use hartex_discord_core::discord::model::guild::ExplicitContentFilter;
 use hartex_discord_core::discord::model::guild::GuildFeature;
 use hartex_discord_core::discord::model::guild::MfaLevel;
+use hartex_discord_core::discord::model::guild::PartialGuild;
 use hartex_discord_core::discord::model::guild::PremiumTier;
 use hartex_discord_core::discord::model::guild::VerificationLevel;
+use hartex_discord_entitycache_core::error::CacheError;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Page;
 use hartex_discord_entitycache_core::traits::Repository;
 use hartex_discord_entitycache_entities::guild::GuildEntity;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::memory;
 
 /// Repository for guild entities.
 pub struct CachedGuildRepository;
 
+impl CachedGuildRepository {
+    /// Updates a guild in the repository from a partial guild.
+    ///
+    /// Partial guilds do not indicate whether a guild is large, which is left untouched. Nothing is
+    /// updated if the guild is not in the repository.
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn update_partial(&self, guild: &PartialGuild) -> CacheResult<()> {
+        CachedGuildUpdateById::bind(
+            i16::from(<DefaultMessageNotificationLevel as Into<u8>>::into(
+                guild.default_message_notifications,
+            )),
+            i16::from(<ExplicitContentFilter as Into<u8>>::into(
+                guild.explicit_content_filter,
+            )),
+            guild
+                .features
+                .iter()
+                .map(|feature| {
+                    <GuildFeature as Into<Cow<'static, str>>>::into(feature.clone()).to_string()
+                })
+                .collect(),
+            guild.icon.map(|hash| hash.to_string()),
+            i16::from(<MfaLevel as Into<u8>>::into(guild.mfa_level)),
+            guild.name.clone(),
+            guild.owner_id.to_string(),
+            guild.premium_subscription_count.map(|count| count as i64),
+            i16::from(<PremiumTier as Into<u8>>::into(guild.premium_tier)),
+            i16::from(<VerificationLevel as Into<u8>>::into(
+                guild.verification_level,
+            )),
+            guild.id.to_string(),
+        )
+        .executor()
+        .await?
+        .execute()
+        .await?;
+
+        memory::GUILDS.remove(&guild.id);
+
+        Ok(())
+    }
+}
+
 impl Repository<GuildEntity> for CachedGuildRepository {
+    type ListKey = ();
+
+    async fn delete(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<()> {
+        CachedGuildDeleteById::bind(id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::GUILDS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<bool> {
+        if memory::GUILDS.contains(&id) {
+            return Ok(true);
+        }
+
+        let exists = CachedGuildExistsById::bind(id.to_string())
+            .executor()
+            .await?
+            .one()
+            .await?;
+
+        Ok(exists)
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_sign_loss)]
     async fn get(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<GuildEntity> {
+        if let Some(entity) = memory::GUILDS.get(&id) {
+            return Ok(entity);
+        }
+
         let data = CachedGuildSelectById::bind(id.to_string())
             .executor()
             .await?
-            .one()
+            .many()
             .await?;
 
-        Ok(GuildEntity::from(data))
+        let entity = data
+            .into_iter()
+            .next()
+            .map(GuildEntity::from)
+            .ok_or(CacheError::NotFound)?;
+        memory::GUILDS.insert(id, entity.clone());
+
+        Ok(entity)
+    }
+
+    async fn get_many(&self, ids: &[<GuildEntity as Entity>::Id]) -> CacheResult<Vec<GuildEntity>> {
+        let data = CachedGuildSelectByIds::bind(ids.iter().map(ToString::to_string).collect())
+            .executor()
+            .await?
+            .many()
+            .await?;
+
+        Ok(data.into_iter().map(GuildEntity::from).collect())
+    }
+
+    async fn list_by(&self, (): Self::ListKey, page: Page) -> CacheResult<Vec<GuildEntity>> {
+        let data = CachedGuildSelectPaginated::bind(i64::from(page.limit), i64::from(page.offset))
+            .executor()
+            .await?
+            .many()
+            .await?;
+
+        Ok(data.into_iter().map(GuildEntity::from).collect())
     }
 
     #[allow(clippy::cast_possible_wrap)]
     async fn upsert(&self, entity: GuildEntity) -> CacheResult<()> {
+        let id = entity.id();
+
         CachedGuildUpsert::bind(
             i16::from(<DefaultMessageNotificationLevel as Into<u8>>::into(
                 entity.default_message_notifications,
@@ -86,6 +204,51 @@ impl Repository<GuildEntity> for CachedGuildRepository {
         .execute()
         .await?;
 
+        memory::GUILDS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<GuildEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let ids = entities.iter().map(Entity::id).collect::<Vec<_>>();
+        let guilds = entities
+            .into_iter()
+            .map(|entity| {
+                json!({
+                    "default_message_notifications": u8::from(entity.default_message_notifications),
+                    "explicit_content_filter": u8::from(entity.explicit_content_filter),
+                    "features": entity
+                        .features
+                        .iter()
+                        .map(|feature| Cow::<str>::from(feature.clone()).to_string())
+                        .collect::<Vec<_>>(),
+                    "icon": entity.icon.map(|hash| hash.to_string()),
+                    "large": entity.large,
+                    "name": entity.name,
+                    "owner_id": entity.owner_id.to_string(),
+                    "id": entity.id.to_string(),
+                    "mfa_level": u8::from(entity.mfa_level),
+                    "premium_subscription_count": entity.premium_subscription_count,
+                    "premium_tier": u8::from(entity.premium_tier),
+                    "verification_level": u8::from(entity.verification_level),
+                })
+            })
+            .collect();
+
+        CachedGuildUpsertMany::bind(Value::Array(guilds).to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        for id in ids {
+            memory::GUILDS.remove(&id);
+        }
+
         Ok(())
     }
 }
@@ -27,8 +27,11 @@
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+pub mod channel;
 pub mod emoji;
 pub mod guild;
 pub mod member;
+pub mod memory;
+mod postgres;
 pub mod role;
 pub mod user;
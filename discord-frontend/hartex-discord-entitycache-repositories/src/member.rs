@@ -20,34 +20,137 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 use chrono::DateTime;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_entitycache_core::error::CacheError;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Page;
 use hartex_discord_entitycache_core::traits::Repository;
 use hartex_discord_entitycache_entities::member::MemberEntity;
+use hartex_database_queries::queries::discord_frontend::cached_member_delete_by_guild_id::CachedMemberDeleteByGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_member_delete_by_user_id_and_guild_id::CachedMemberDeleteByUserIdAndGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_member_exists_by_user_id_and_guild_id::CachedMemberExistsByUserIdAndGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_member_select_by_guild_id_paginated::CachedMemberSelectByGuildIdPaginated;
 use hartex_database_queries::queries::discord_frontend::cached_member_select_by_user_id_and_guild_id::CachedMemberSelectByUserIdAndGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_member_select_by_user_ids_and_guild_ids::CachedMemberSelectByUserIdsAndGuildIds;
 use hartex_database_queries::queries::discord_frontend::cached_member_upsert::CachedMemberUpsert;
+use hartex_database_queries::queries::discord_frontend::cached_member_upsert_many::CachedMemberUpsertMany;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::memory;
 
 /// Repository for member entities.
 pub struct CachedMemberRepository;
 
+impl CachedMemberRepository {
+    /// Deletes every member of a guild from the repository.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        CachedMemberDeleteByGuildId::bind(guild_id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::MEMBERS.remove_where(|(entity_guild_id, _), _| *entity_guild_id == guild_id);
+
+        Ok(())
+    }
+}
+
 impl Repository<MemberEntity> for CachedMemberRepository {
+    type ListKey = Id<GuildMarker>;
+
+    async fn delete(&self, (guild_id, user_id): <MemberEntity as Entity>::Id) -> CacheResult<()> {
+        CachedMemberDeleteByUserIdAndGuildId::bind(user_id.to_string(), guild_id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::MEMBERS.remove(&(guild_id, user_id));
+
+        Ok(())
+    }
+
+    async fn exists(&self, (guild_id, user_id): <MemberEntity as Entity>::Id) -> CacheResult<bool> {
+        if memory::MEMBERS.contains(&(guild_id, user_id)) {
+            return Ok(true);
+        }
+
+        let exists =
+            CachedMemberExistsByUserIdAndGuildId::bind(user_id.to_string(), guild_id.to_string())
+                .executor()
+                .await?
+                .one()
+                .await?;
+
+        Ok(exists)
+    }
+
     #[allow(clippy::cast_sign_loss)]
     async fn get(
         &self,
         (guild_id, user_id): <MemberEntity as Entity>::Id,
     ) -> CacheResult<MemberEntity> {
+        if let Some(entity) = memory::MEMBERS.get(&(guild_id, user_id)) {
+            return Ok(entity);
+        }
+
         let data =
             CachedMemberSelectByUserIdAndGuildId::bind(user_id.to_string(), guild_id.to_string())
                 .executor()
                 .await?
-                .one()
+                .many()
                 .await?;
 
-        Ok(MemberEntity::from(data))
+        let entity = data
+            .into_iter()
+            .next()
+            .map(MemberEntity::from)
+            .ok_or(CacheError::NotFound)?;
+        memory::MEMBERS.insert((guild_id, user_id), entity.clone());
+
+        Ok(entity)
+    }
+
+    async fn get_many(
+        &self,
+        ids: &[<MemberEntity as Entity>::Id],
+    ) -> CacheResult<Vec<MemberEntity>> {
+        let (guild_ids, user_ids): (Vec<_>, Vec<_>) = ids
+            .iter()
+            .map(|(guild_id, user_id)| (guild_id.to_string(), user_id.to_string()))
+            .unzip();
+        let data = CachedMemberSelectByUserIdsAndGuildIds::bind(user_ids, guild_ids)
+            .executor()
+            .await?
+            .many()
+            .await?;
+
+        Ok(data.into_iter().map(MemberEntity::from).collect())
+    }
+
+    async fn list_by(&self, guild_id: Self::ListKey, page: Page) -> CacheResult<Vec<MemberEntity>> {
+        let data = CachedMemberSelectByGuildIdPaginated::bind(
+            guild_id.to_string(),
+            i64::from(page.limit),
+            i64::from(page.offset),
+        )
+        .executor()
+        .await?
+        .many()
+        .await?;
+
+        Ok(data.into_iter().map(MemberEntity::from).collect())
     }
 
     #[allow(clippy::cast_possible_wrap)]
     async fn upsert(&self, entity: MemberEntity) -> CacheResult<()> {
+        let id = entity.id();
+
         CachedMemberUpsert::bind(
             entity.flags.bits() as i64,
             entity
@@ -67,6 +170,47 @@ impl Repository<MemberEntity> for CachedMemberRepository {
         .execute()
         .await?;
 
+        memory::MEMBERS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<MemberEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let ids = entities.iter().map(Entity::id).collect::<Vec<_>>();
+        let members = entities
+            .into_iter()
+            .map(|entity| {
+                json!({
+                    "flags": entity.flags.bits(),
+                    "joined_at": entity
+                        .joined_at
+                        .map(|timestamp| timestamp.iso_8601().to_string()),
+                    "nick": entity.nick,
+                    "user_id": entity.user_id.to_string(),
+                    "guild_id": entity.guild_id.to_string(),
+                    "roles": entity
+                        .roles
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+
+        CachedMemberUpsertMany::bind(Value::Array(members).to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        for id in ids {
+            memory::MEMBERS.remove(&id);
+        }
+
         Ok(())
     }
 }
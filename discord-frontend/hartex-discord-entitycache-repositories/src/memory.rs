@@ -0,0 +1,459 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # In-Memory Tier
+//!
+//! An optional tier kept in process memory in front of the entity cache in the database. Entities
+//! read through a repository are kept in the tier for a limited time, and are dropped from it when
+//! they are written to through a repository or invalidated by the entitycache update service.
+//!
+//! Each entity type has its own tier holding up to `ENTITY_CACHE_MEMORY_CAPACITY` entities for
+//! `ENTITY_CACHE_MEMORY_TTL_SECONDS` seconds (60 by default). The tiers are disabled unless a
+//! capacity is set.
+
+use std::collections::HashMap;
+use std::env;
+use std::hash::Hash;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::EmojiMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
+use hartex_discord_entitycache_entities::emoji::EmojiEntity;
+use hartex_discord_entitycache_entities::guild::GuildEntity;
+use hartex_discord_entitycache_entities::member::MemberEntity;
+use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_entities::user::UserEntity;
+use hartex_discord_internal_events::cache::CacheInvalidation;
+
+/// The time entities are kept in a tier for if not configured otherwise.
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+/// The in-memory tier of channel entities.
+pub static CHANNELS: LazyLock<MemoryTier<Id<ChannelMarker>, ChannelEntity>> =
+    LazyLock::new(MemoryTier::from_env);
+
+/// The in-memory tier of emoji entities.
+pub static EMOJIS: LazyLock<MemoryTier<Id<EmojiMarker>, EmojiEntity>> =
+    LazyLock::new(MemoryTier::from_env);
+
+/// The in-memory tier of guild entities.
+pub static GUILDS: LazyLock<MemoryTier<Id<GuildMarker>, GuildEntity>> =
+    LazyLock::new(MemoryTier::from_env);
+
+/// The in-memory tier of member entities.
+pub static MEMBERS: LazyLock<MemoryTier<(Id<GuildMarker>, Id<UserMarker>), MemberEntity>> =
+    LazyLock::new(MemoryTier::from_env);
+
+/// The in-memory tier of role entities.
+pub static ROLES: LazyLock<MemoryTier<(Id<GuildMarker>, Id<RoleMarker>), RoleEntity>> =
+    LazyLock::new(MemoryTier::from_env);
+
+/// The in-memory tier of user entities.
+pub static USERS: LazyLock<MemoryTier<Id<UserMarker>, UserEntity>> =
+    LazyLock::new(MemoryTier::from_env);
+
+/// Returns whether any of the in-memory tiers is enabled.
+#[must_use]
+pub fn enabled() -> bool {
+    CHANNELS.enabled()
+        || EMOJIS.enabled()
+        || GUILDS.enabled()
+        || MEMBERS.enabled()
+        || ROLES.enabled()
+        || USERS.enabled()
+}
+
+/// Drops the entities affected by a cache invalidation from the in-memory tiers.
+pub fn invalidate(invalidation: &CacheInvalidation) {
+    match *invalidation {
+        CacheInvalidation::All => {
+            CHANNELS.clear();
+            EMOJIS.clear();
+            GUILDS.clear();
+            MEMBERS.clear();
+            ROLES.clear();
+            USERS.clear();
+        }
+        CacheInvalidation::Channel { channel_id } => {
+            CHANNELS.remove(&channel_id);
+            CHANNELS.remove_where(|_, channel| channel.parent_id == Some(channel_id));
+        }
+        CacheInvalidation::Emoji { emoji_id } => EMOJIS.remove(&emoji_id),
+        CacheInvalidation::Guild { guild_id } => {
+            CHANNELS.remove_where(|_, channel| channel.guild_id == guild_id);
+            EMOJIS.remove_where(|_, emoji| emoji.guild_id == guild_id);
+            GUILDS.remove(&guild_id);
+            MEMBERS.remove_where(|(member_guild_id, _), _| *member_guild_id == guild_id);
+            ROLES.remove_where(|(role_guild_id, _), _| *role_guild_id == guild_id);
+        }
+        CacheInvalidation::Member { guild_id, user_id } => MEMBERS.remove(&(guild_id, user_id)),
+        CacheInvalidation::Role { guild_id, role_id } => ROLES.remove(&(guild_id, role_id)),
+        CacheInvalidation::User { user_id } => USERS.remove(&user_id),
+    }
+}
+
+/// A bounded in-memory tier of entities.
+///
+/// When the tier is full, the least recently used entity is evicted to make room for a new one.
+pub struct MemoryTier<K, V> {
+    capacity: usize,
+    entries: Mutex<MemoryTierEntries<K, V>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    ttl: Duration,
+}
+
+impl<K, V> MemoryTier<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    /// Create a new in-memory tier holding up to the given number of entities for the given
+    /// duration.
+    ///
+    /// A capacity of zero disables the tier.
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(MemoryTierEntries::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            ttl,
+        }
+    }
+
+    /// Create a new in-memory tier configured through the environment.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let capacity = env::var("ENTITY_CACHE_MEMORY_CAPACITY")
+            .ok()
+            .and_then(|capacity| capacity.parse().ok())
+            .unwrap_or_default();
+        let ttl = env::var("ENTITY_CACHE_MEMORY_TTL_SECONDS")
+            .ok()
+            .and_then(|seconds| seconds.parse().ok())
+            .map_or(DEFAULT_TTL, Duration::from_secs);
+
+        Self::new(capacity, ttl)
+    }
+
+    /// Drops every entity from the tier.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Returns whether an entity that has not yet expired is in the tier.
+    ///
+    /// This does not count towards the hits and misses of the tier.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn contains(&self, key: &K) -> bool {
+        if !self.enabled() {
+            return false;
+        }
+
+        let now = Instant::now();
+        let entries = self.entries.lock().unwrap();
+        entries
+            .index_of(key)
+            .is_some_and(|index| entries.slots[index].expires_at > now)
+    }
+
+    /// Returns whether the tier is enabled.
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Returns an entity from the tier if it is in the tier and has not yet expired.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<V> {
+        if !self.enabled() {
+            return None;
+        }
+
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+
+        let value = match entries.index_of(key) {
+            Some(index) if entries.slots[index].expires_at > now => {
+                entries.touch(index);
+                Some(entries.slots[index].value.clone())
+            }
+            Some(index) => {
+                entries.remove_at(index);
+                None
+            }
+            None => None,
+        };
+
+        if value.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+
+        value
+    }
+
+    /// Puts an entity into the tier, evicting the least recently used entity if the tier is full.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn insert(&self, key: K, value: V) {
+        if !self.enabled() {
+            return;
+        }
+
+        let expires_at = Instant::now() + self.ttl;
+        let mut entries = self.entries.lock().unwrap();
+
+        if let Some(index) = entries.index_of(&key) {
+            let entry = &mut entries.slots[index];
+            entry.expires_at = expires_at;
+            entry.value = value;
+
+            entries.touch(index);
+            return;
+        }
+
+        if entries.len() >= self.capacity {
+            entries.remove_least_recently_used();
+        }
+
+        entries.push(key, value, expires_at);
+    }
+
+    /// Drops an entity from the tier.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn remove(&self, key: &K) {
+        if !self.enabled() {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(index) = entries.index_of(key) {
+            entries.remove_at(index);
+        }
+    }
+
+    /// Drops every entity matching the predicate from the tier.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn remove_where<F>(&self, predicate: F)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        if !self.enabled() {
+            return;
+        }
+
+        self.entries.lock().unwrap().remove_where(predicate);
+    }
+
+    /// Returns the hit and miss counters of the tier, along with the number of entities in it.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn stats(&self) -> MemoryTierStats {
+        MemoryTierStats {
+            entries: self.entries.lock().unwrap().len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Statistics of an in-memory tier.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemoryTierStats {
+    /// The number of entities in the tier, including those that have expired but not yet been
+    /// dropped.
+    pub entries: usize,
+    /// The number of reads served from the tier.
+    pub hits: u64,
+    /// The number of reads not served from the tier.
+    pub misses: u64,
+}
+
+/// The entities in an in-memory tier.
+///
+/// The entities are kept in a doubly linked list ordered from the most to the least recently used,
+/// with the links being indices into a vector of slots so that every operation but
+/// [`MemoryTierEntries::remove_where`] takes constant time.
+struct MemoryTierEntries<K, V> {
+    /// The slot of the most recently used entity.
+    head: Option<usize>,
+    map: HashMap<K, usize>,
+    slots: Vec<MemoryTierEntry<K, V>>,
+    /// The slot of the least recently used entity.
+    tail: Option<usize>,
+}
+
+impl<K, V> MemoryTierEntries<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    fn new() -> Self {
+        Self {
+            head: None,
+            map: HashMap::new(),
+            slots: Vec::new(),
+            tail: None,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.head = None;
+        self.map.clear();
+        self.slots.clear();
+        self.tail = None;
+    }
+
+    fn index_of(&self, key: &K) -> Option<usize> {
+        self.map.get(key).copied()
+    }
+
+    fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Adds a new entity as the most recently used one.
+    fn push(&mut self, key: K, value: V, expires_at: Instant) {
+        let index = self.slots.len();
+        self.map.insert(key.clone(), index);
+        self.slots.push(MemoryTierEntry {
+            expires_at,
+            key,
+            next: None,
+            previous: None,
+            value,
+        });
+
+        self.link_front(index);
+    }
+
+    /// Drops the entity in a slot, moving the entity in the last slot into it.
+    fn remove_at(&mut self, index: usize) {
+        self.unlink(index);
+
+        let entry = self.slots.swap_remove(index);
+        self.map.remove(&entry.key);
+
+        if index == self.slots.len() {
+            return;
+        }
+
+        let (previous, next) = (self.slots[index].previous, self.slots[index].next);
+        match previous {
+            Some(previous) => self.slots[previous].next = Some(index),
+            None => self.head = Some(index),
+        }
+        match next {
+            Some(next) => self.slots[next].previous = Some(index),
+            None => self.tail = Some(index),
+        }
+
+        if let Some(moved) = self.map.get_mut(&self.slots[index].key) {
+            *moved = index;
+        }
+    }
+
+    fn remove_least_recently_used(&mut self) {
+        if let Some(tail) = self.tail {
+            self.remove_at(tail);
+        }
+    }
+
+    fn remove_where<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let keys = self
+            .slots
+            .iter()
+            .filter(|entry| predicate(&entry.key, &entry.value))
+            .map(|entry| entry.key.clone())
+            .collect::<Vec<_>>();
+
+        for key in keys {
+            if let Some(index) = self.index_of(&key) {
+                self.remove_at(index);
+            }
+        }
+    }
+
+    /// Marks the entity in a slot as the most recently used one.
+    fn touch(&mut self, index: usize) {
+        if self.head != Some(index) {
+            self.unlink(index);
+            self.link_front(index);
+        }
+    }
+
+    fn link_front(&mut self, index: usize) {
+        self.slots[index].previous = None;
+        self.slots[index].next = self.head;
+
+        match self.head {
+            Some(head) => self.slots[head].previous = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let (previous, next) = (self.slots[index].previous, self.slots[index].next);
+
+        match previous {
+            Some(previous) => self.slots[previous].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.slots[next].previous = previous,
+            None => self.tail = previous,
+        }
+
+        self.slots[index].previous = None;
+        self.slots[index].next = None;
+    }
+}
+
+/// An entity in an in-memory tier.
+struct MemoryTierEntry<K, V> {
+    expires_at: Instant,
+    key: K,
+    /// The slot of the next less recently used entity.
+    next: Option<usize>,
+    /// The slot of the next more recently used entity.
+    previous: Option<usize>,
+    value: V,
+}
@@ -0,0 +1,44 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Postgres Connections
+//!
+//! The generated queries each run on a connection of their own, so queries that have to be run
+//! together in a single transaction are run on a connection opened here instead.
+
+use std::env;
+
+use hartex_discord_core::tokio::task::spawn;
+use hartex_discord_entitycache_core::error::CacheResult;
+use tokio_postgres::Client;
+use tokio_postgres::NoTls;
+
+/// Opens a connection to the database of the discord frontend.
+///
+/// The connection is driven in the background until the returned client is dropped.
+pub(crate) async fn connect() -> CacheResult<Client> {
+    let url = env::var("DISCORD_FRONTEND_PGSQL_URL")?;
+    let (client, connection) = tokio_postgres::connect(&url, NoTls).await?;
+    spawn(connection);
+
+    Ok(client)
+}
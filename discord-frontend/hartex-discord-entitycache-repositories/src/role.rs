@@ -22,21 +22,47 @@
 
 use std::str::FromStr;
 
+use hartex_database_queries::queries::discord_frontend::cached_role_delete_by_guild_id::CachedRoleDeleteByGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_role_delete_by_id_and_guild_id::CachedRoleDeleteByIdAndGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_role_exists_by_id_and_guild_id::CachedRoleExistsByIdAndGuildId;
 use hartex_database_queries::queries::discord_frontend::cached_role_select_by_guild_id::CachedRoleSelectByGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_role_select_by_guild_id_paginated::CachedRoleSelectByGuildIdPaginated;
 use hartex_database_queries::queries::discord_frontend::cached_role_select_by_id_and_guild_id::CachedRoleSelectByIdAndGuildId;
+use hartex_database_queries::queries::discord_frontend::cached_role_select_by_ids_and_guild_ids::CachedRoleSelectByIdsAndGuildIds;
 use hartex_database_queries::queries::discord_frontend::cached_role_upsert::CachedRoleUpsert;
+use hartex_database_queries::queries::discord_frontend::cached_role_upsert_many::CachedRoleUpsertMany;
 use hartex_discord_core::discord::model::id::Id;
 use hartex_discord_core::discord::model::id::marker::GuildMarker;
 use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_entitycache_core::error::CacheError;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Page;
 use hartex_discord_entitycache_core::traits::Repository;
 use hartex_discord_entitycache_entities::role::RoleEntity;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::memory;
 
 /// Repository for role entities.
 pub struct CachedRoleRepository;
 
 impl CachedRoleRepository {
+    /// Deletes every role of a guild from the repository.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        CachedRoleDeleteByGuildId::bind(guild_id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::ROLES.remove_where(|(entity_guild_id, _), _| *entity_guild_id == guild_id);
+
+        Ok(())
+    }
+
     // todo: add relationship to get all roles from a guild
     #[allow(clippy::missing_errors_doc)]
     #[allow(clippy::missing_panics_doc)]
@@ -58,23 +84,92 @@ impl CachedRoleRepository {
 }
 
 impl Repository<RoleEntity> for CachedRoleRepository {
+    type ListKey = Id<GuildMarker>;
+
+    async fn delete(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<()> {
+        CachedRoleDeleteByIdAndGuildId::bind(id.to_string(), guild_id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::ROLES.remove(&(guild_id, id));
+
+        Ok(())
+    }
+
+    async fn exists(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<bool> {
+        if memory::ROLES.contains(&(guild_id, id)) {
+            return Ok(true);
+        }
+
+        let exists = CachedRoleExistsByIdAndGuildId::bind(id.to_string(), guild_id.to_string())
+            .executor()
+            .await?
+            .one()
+            .await?;
+
+        Ok(exists)
+    }
+
     #[allow(clippy::cast_lossless)]
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_sign_loss)]
     async fn get(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<RoleEntity> {
+        if let Some(entity) = memory::ROLES.get(&(guild_id, id)) {
+            return Ok(entity);
+        }
+
         let data = CachedRoleSelectByIdAndGuildId::bind(id.to_string(), guild_id.to_string())
             .executor()
             .await?
-            .one()
+            .many()
             .await?;
 
-        Ok(RoleEntity::from(data))
+        let entity = data
+            .into_iter()
+            .next()
+            .map(RoleEntity::from)
+            .ok_or(CacheError::NotFound)?;
+        memory::ROLES.insert((guild_id, id), entity.clone());
+
+        Ok(entity)
+    }
+
+    async fn get_many(&self, ids: &[<RoleEntity as Entity>::Id]) -> CacheResult<Vec<RoleEntity>> {
+        let (guild_ids, ids): (Vec<_>, Vec<_>) = ids
+            .iter()
+            .map(|(guild_id, id)| (guild_id.to_string(), id.to_string()))
+            .unzip();
+        let data = CachedRoleSelectByIdsAndGuildIds::bind(ids, guild_ids)
+            .executor()
+            .await?
+            .many()
+            .await?;
+
+        Ok(data.into_iter().map(RoleEntity::from).collect())
+    }
+
+    async fn list_by(&self, guild_id: Self::ListKey, page: Page) -> CacheResult<Vec<RoleEntity>> {
+        let data = CachedRoleSelectByGuildIdPaginated::bind(
+            guild_id.to_string(),
+            i64::from(page.limit),
+            i64::from(page.offset),
+        )
+        .executor()
+        .await?
+        .many()
+        .await?;
+
+        Ok(data.into_iter().map(RoleEntity::from).collect())
     }
 
     #[allow(clippy::cast_lossless)]
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_sign_loss)]
     async fn upsert(&self, entity: RoleEntity) -> CacheResult<()> {
+        let id = entity.id();
+
         CachedRoleUpsert::bind(
             entity.color as i64,
             entity.icon.map(|hash| hash.to_string()),
@@ -84,6 +179,7 @@ impl Repository<RoleEntity> for CachedRoleRepository {
             entity.hoist,
             entity.managed,
             entity.mentionable,
+            entity.permissions.bits() as i64,
             entity.position as i32,
         )
         .executor()
@@ -91,6 +187,45 @@ impl Repository<RoleEntity> for CachedRoleRepository {
         .execute()
         .await?;
 
+        memory::ROLES.remove(&id);
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<RoleEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let ids = entities.iter().map(Entity::id).collect::<Vec<_>>();
+        let roles = entities
+            .into_iter()
+            .map(|entity| {
+                json!({
+                    "color": entity.color,
+                    "icon": entity.icon.map(|hash| hash.to_string()),
+                    "id": entity.id.to_string(),
+                    "guild_id": entity.guild_id.to_string(),
+                    "flags": entity.flags.bits(),
+                    "hoist": entity.hoist,
+                    "managed": entity.managed,
+                    "mentionable": entity.mentionable,
+                    "permissions": entity.permissions.bits(),
+                    "position": entity.position,
+                })
+            })
+            .collect();
+
+        CachedRoleUpsertMany::bind(Value::Array(roles).to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        for id in ids {
+            memory::ROLES.remove(&id);
+        }
+
         Ok(())
     }
 }
@@ -20,28 +20,126 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use hartex_database_queries::queries::discord_frontend::cached_user_delete_by_id::CachedUserDeleteById;
+use hartex_database_queries::queries::discord_frontend::cached_user_exists_by_id::CachedUserExistsById;
 use hartex_database_queries::queries::discord_frontend::cached_user_select_by_id::CachedUserSelectById;
+use hartex_database_queries::queries::discord_frontend::cached_user_select_by_ids::CachedUserSelectByIds;
+use hartex_database_queries::queries::discord_frontend::cached_user_select_paginated::CachedUserSelectPaginated;
+use hartex_database_queries::queries::discord_frontend::cached_user_update_by_id::CachedUserUpdateById;
 use hartex_database_queries::queries::discord_frontend::cached_user_upsert::CachedUserUpsert;
+use hartex_database_queries::queries::discord_frontend::cached_user_upsert_many::CachedUserUpsertMany;
+use hartex_discord_core::discord::model::user::CurrentUser;
+use hartex_discord_entitycache_core::error::CacheError;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Page;
 use hartex_discord_entitycache_core::traits::Repository;
 use hartex_discord_entitycache_entities::user::UserEntity;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::memory;
 
 /// Repository for user entities.
 pub struct CachedUserRepository;
 
+impl CachedUserRepository {
+    /// Updates a user in the repository from the current user.
+    ///
+    /// Nothing is updated if the user is not in the repository.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn update_current(&self, user: &CurrentUser) -> CacheResult<()> {
+        CachedUserUpdateById::bind(
+            user.avatar.map(|hash| hash.to_string()),
+            user.bot,
+            user.discriminator.to_string(),
+            user.name.clone(),
+            user.id.to_string(),
+        )
+        .executor()
+        .await?
+        .execute()
+        .await?;
+
+        memory::USERS.remove(&user.id);
+
+        Ok(())
+    }
+}
+
 impl Repository<UserEntity> for CachedUserRepository {
+    type ListKey = ();
+
+    async fn delete(&self, id: <UserEntity as Entity>::Id) -> CacheResult<()> {
+        CachedUserDeleteById::bind(id.to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        memory::USERS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn exists(&self, id: <UserEntity as Entity>::Id) -> CacheResult<bool> {
+        if memory::USERS.contains(&id) {
+            return Ok(true);
+        }
+
+        let exists = CachedUserExistsById::bind(id.to_string())
+            .executor()
+            .await?
+            .one()
+            .await?;
+
+        Ok(exists)
+    }
+
     async fn get(&self, id: <UserEntity as Entity>::Id) -> CacheResult<UserEntity> {
+        if let Some(entity) = memory::USERS.get(&id) {
+            return Ok(entity);
+        }
+
         let data = CachedUserSelectById::bind(id.to_string())
             .executor()
             .await?
-            .one()
+            .many()
+            .await?;
+
+        let entity = data
+            .into_iter()
+            .next()
+            .map(UserEntity::from)
+            .ok_or(CacheError::NotFound)?;
+        memory::USERS.insert(id, entity.clone());
+
+        Ok(entity)
+    }
+
+    async fn get_many(&self, ids: &[<UserEntity as Entity>::Id]) -> CacheResult<Vec<UserEntity>> {
+        let data = CachedUserSelectByIds::bind(ids.iter().map(ToString::to_string).collect())
+            .executor()
+            .await?
+            .many()
+            .await?;
+
+        Ok(data.into_iter().map(UserEntity::from).collect())
+    }
+
+    async fn list_by(&self, (): Self::ListKey, page: Page) -> CacheResult<Vec<UserEntity>> {
+        let data = CachedUserSelectPaginated::bind(i64::from(page.limit), i64::from(page.offset))
+            .executor()
+            .await?
+            .many()
             .await?;
 
-        Ok(UserEntity::from(data))
+        Ok(data.into_iter().map(UserEntity::from).collect())
     }
 
     async fn upsert(&self, entity: UserEntity) -> CacheResult<()> {
+        let id = entity.id();
+
         CachedUserUpsert::bind(
             entity.avatar.map(|hash| hash.to_string()),
             entity.id.to_string(),
@@ -55,6 +153,41 @@ impl Repository<UserEntity> for CachedUserRepository {
         .execute()
         .await?;
 
+        memory::USERS.remove(&id);
+
+        Ok(())
+    }
+
+    async fn upsert_many(&self, entities: Vec<UserEntity>) -> CacheResult<()> {
+        if entities.is_empty() {
+            return Ok(());
+        }
+
+        let ids = entities.iter().map(Entity::id).collect::<Vec<_>>();
+        let users = entities
+            .into_iter()
+            .map(|entity| {
+                json!({
+                    "avatar": entity.avatar.map(|hash| hash.to_string()),
+                    "id": entity.id.to_string(),
+                    "bot": entity.bot,
+                    "name": entity.name,
+                    "discriminator": entity.discriminator.to_string(),
+                    "global_name": entity.global_name,
+                })
+            })
+            .collect();
+
+        CachedUserUpsertMany::bind(Value::Array(users).to_string())
+            .executor()
+            .await?
+            .execute()
+            .await?;
+
+        for id in ids {
+            memory::USERS.remove(&id);
+        }
+
         Ok(())
     }
 }
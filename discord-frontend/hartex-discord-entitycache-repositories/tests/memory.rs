@@ -0,0 +1,133 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use hartex_discord_entitycache_repositories::memory::MemoryTier;
+use hartex_discord_entitycache_repositories::memory::MemoryTierStats;
+
+const TTL: Duration = Duration::from_secs(60);
+
+#[test]
+fn disabled_tier_keeps_nothing() {
+    let tier = MemoryTier::new(0, TTL);
+    tier.insert(1, "one");
+
+    assert!(!tier.contains(&1));
+    assert_eq!(tier.get(&1), None);
+}
+
+#[test]
+fn full_tier_evicts_least_recently_used() {
+    let tier = MemoryTier::new(2, TTL);
+    tier.insert(1, "one");
+    tier.insert(2, "two");
+    assert_eq!(tier.get(&1), Some("one"));
+
+    tier.insert(3, "three");
+
+    assert!(tier.contains(&1));
+    assert!(!tier.contains(&2));
+    assert!(tier.contains(&3));
+}
+
+#[test]
+fn reinserting_does_not_evict() {
+    let tier = MemoryTier::new(2, TTL);
+    tier.insert(1, "one");
+    tier.insert(2, "two");
+    tier.insert(1, "uno");
+
+    assert_eq!(tier.get(&1), Some("uno"));
+    assert_eq!(tier.get(&2), Some("two"));
+
+    tier.insert(3, "three");
+
+    assert!(!tier.contains(&1));
+    assert!(tier.contains(&2));
+    assert!(tier.contains(&3));
+}
+
+#[test]
+fn removing_keeps_recency_order() {
+    let tier = MemoryTier::new(3, TTL);
+    tier.insert(1, "one");
+    tier.insert(2, "two");
+    tier.insert(3, "three");
+    tier.remove(&2);
+
+    tier.insert(4, "four");
+    tier.insert(5, "five");
+
+    assert!(!tier.contains(&1));
+    assert!(!tier.contains(&2));
+    assert!(tier.contains(&3));
+    assert!(tier.contains(&4));
+    assert!(tier.contains(&5));
+}
+
+#[test]
+fn remove_where_drops_matching_entities() {
+    let tier = MemoryTier::new(4, TTL);
+    for key in 1..=4 {
+        tier.insert(key, key * 10);
+    }
+
+    tier.remove_where(|key, _| key % 2 == 0);
+    tier.insert(5, 50);
+    tier.insert(6, 60);
+    tier.insert(7, 70);
+
+    assert!(!tier.contains(&1));
+    assert!(!tier.contains(&2));
+    assert!(tier.contains(&3));
+    assert!(!tier.contains(&4));
+    assert_eq!(tier.stats().entries, 4);
+}
+
+#[test]
+fn expired_entities_are_misses() {
+    let tier = MemoryTier::new(2, Duration::ZERO);
+    tier.insert(1, "one");
+
+    assert!(!tier.contains(&1));
+    assert_eq!(tier.get(&1), None);
+    assert_eq!(tier.stats().entries, 0);
+}
+
+#[test]
+fn stats_count_hits_and_misses() {
+    let tier = MemoryTier::new(2, TTL);
+    tier.insert(1, "one");
+    let _ = tier.get(&1);
+    let _ = tier.get(&1);
+    let _ = tier.get(&2);
+
+    assert_eq!(
+        tier.stats(),
+        MemoryTierStats {
+            entries: 1,
+            hits: 2,
+            misses: 1,
+        }
+    );
+}
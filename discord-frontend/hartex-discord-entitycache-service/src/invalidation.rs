@@ -0,0 +1,147 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Cache Invalidations
+//!
+//! Services keeping cached entities in memory are told which of them have become stale once the
+//! entitycache has been updated using an event.
+
+use hartex_discord_core::discord::model::gateway::event::DispatchEvent;
+use hartex_discord_core::discord::model::gateway::event::GatewayEvent;
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildCreate;
+use hartex_discord_internal_events::cache::CacheInvalidation;
+
+/// Returns the cached entities to invalidate after updating the entitycache using an event.
+pub fn invalidations(event: &GatewayEvent) -> Vec<CacheInvalidation> {
+    let GatewayEvent::Dispatch(_, dispatch) = event else {
+        return Vec::new();
+    };
+
+    match dispatch {
+        DispatchEvent::ChannelCreate(channel_create) => vec![CacheInvalidation::Channel {
+            channel_id: channel_create.id,
+        }],
+        DispatchEvent::ChannelDelete(channel_delete) => vec![CacheInvalidation::Channel {
+            channel_id: channel_delete.id,
+        }],
+        DispatchEvent::ChannelUpdate(channel_update) => vec![CacheInvalidation::Channel {
+            channel_id: channel_update.id,
+        }],
+        DispatchEvent::GuildCreate(guild_create) => match &**guild_create {
+            GuildCreate::Available(guild) => vec![CacheInvalidation::Guild { guild_id: guild.id }],
+            GuildCreate::Unavailable(_) => Vec::new(),
+        },
+        DispatchEvent::GuildDelete(guild_delete)
+            if !guild_delete.unavailable.unwrap_or_default() =>
+        {
+            vec![CacheInvalidation::Guild {
+                guild_id: guild_delete.id,
+            }]
+        }
+        // the emojis removed from the guild are not in the event, so the whole guild is invalidated
+        DispatchEvent::GuildEmojisUpdate(guild_emojis_update) => vec![CacheInvalidation::Guild {
+            guild_id: guild_emojis_update.guild_id,
+        }],
+        DispatchEvent::GuildUpdate(guild_update) => vec![CacheInvalidation::Guild {
+            guild_id: guild_update.id,
+        }],
+        DispatchEvent::MemberAdd(member_add) => vec![
+            CacheInvalidation::Member {
+                guild_id: member_add.guild_id,
+                user_id: member_add.member.user.id,
+            },
+            CacheInvalidation::User {
+                user_id: member_add.member.user.id,
+            },
+        ],
+        DispatchEvent::MemberChunk(member_chunk) => member_chunk
+            .members
+            .iter()
+            .flat_map(|member| {
+                [
+                    CacheInvalidation::Member {
+                        guild_id: member_chunk.guild_id,
+                        user_id: member.user.id,
+                    },
+                    CacheInvalidation::User {
+                        user_id: member.user.id,
+                    },
+                ]
+            })
+            .collect(),
+        DispatchEvent::MemberRemove(member_remove) => vec![CacheInvalidation::Member {
+            guild_id: member_remove.guild_id,
+            user_id: member_remove.user.id,
+        }],
+        DispatchEvent::MemberUpdate(member_update) => vec![
+            CacheInvalidation::Member {
+                guild_id: member_update.guild_id,
+                user_id: member_update.user.id,
+            },
+            CacheInvalidation::User {
+                user_id: member_update.user.id,
+            },
+        ],
+        DispatchEvent::RoleCreate(role_create) => vec![CacheInvalidation::Role {
+            guild_id: role_create.guild_id,
+            role_id: role_create.role.id,
+        }],
+        DispatchEvent::RoleDelete(role_delete) => vec![CacheInvalidation::Role {
+            guild_id: role_delete.guild_id,
+            role_id: role_delete.role_id,
+        }],
+        DispatchEvent::RoleUpdate(role_update) => vec![CacheInvalidation::Role {
+            guild_id: role_update.guild_id,
+            role_id: role_update.role.id,
+        }],
+        DispatchEvent::ThreadCreate(thread_create) => vec![CacheInvalidation::Channel {
+            channel_id: thread_create.id,
+        }],
+        DispatchEvent::ThreadDelete(thread_delete) => vec![CacheInvalidation::Channel {
+            channel_id: thread_delete.id,
+        }],
+        // a sync without parent channels replaces every thread of the guild, so threads no
+        // longer active can only be dropped by invalidating the whole guild
+        DispatchEvent::ThreadListSync(thread_list_sync)
+            if thread_list_sync.channel_ids.is_empty() =>
+        {
+            vec![CacheInvalidation::Guild {
+                guild_id: thread_list_sync.guild_id,
+            }]
+        }
+        // invalidating a parent channel also drops every thread under it
+        DispatchEvent::ThreadListSync(thread_list_sync) => thread_list_sync
+            .channel_ids
+            .iter()
+            .copied()
+            .chain(thread_list_sync.threads.iter().map(|thread| thread.id))
+            .map(|channel_id| CacheInvalidation::Channel { channel_id })
+            .collect(),
+        DispatchEvent::ThreadUpdate(thread_update) => vec![CacheInvalidation::Channel {
+            channel_id: thread_update.id,
+        }],
+        DispatchEvent::UserUpdate(user_update) => vec![CacheInvalidation::User {
+            user_id: user_update.0.id,
+        }],
+        _ => Vec::new(),
+    }
+}
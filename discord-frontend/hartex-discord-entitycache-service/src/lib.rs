@@ -0,0 +1,130 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Entitycache Update Service
+//!
+//! The service updating the entitycache when event payloads are received from Discord via the
+//! websocket gateway. It runs either as its own process, or together with the other components in
+//! a single process.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use hartex_discord_internal_events::InternalEvent;
+use hartex_discord_internal_events::inbound::InboundGatewayPayloadKey;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
+use hartex_discord_utils::dispatch::EventDispatcher;
+use hartex_discord_utils::inbound::InboundPayload;
+use hartex_kafka_utils::deadletter::DeadLetter;
+use hartex_log::log;
+
+mod entitycache;
+mod invalidation;
+
+/// The consumer group of the service consuming inbound gateway payloads.
+const INBOUND_CONSUMER_GROUP_ID: &str =
+    "com.github.teamhartex.hartex.inbound.gateway.payload.consumer";
+
+/// Run the entitycache update service until the inbound gateway payload channel is closed.
+///
+/// # Errors
+///
+/// Returns an error if the service cannot be started.
+pub async fn run(transport: Transport) -> miette::Result<()> {
+    let channel = Channel::InboundGatewayPayloadCache;
+    let mut subscription = transport.subscribe(channel, INBOUND_CONSUMER_GROUP_ID)?;
+
+    let dispatcher = EventDispatcher::from_env("ENTITYCACHE_MAX_IN_FLIGHT_EVENTS")?;
+
+    while let Some(record) = subscription.next().await {
+        let inbound = match InboundPayload::decode(
+            InboundGatewayPayloadKey::shard_of(record.key.as_deref()),
+            &record.payload,
+        ) {
+            Ok(inbound) => inbound,
+            Err(error) => {
+                log::warn!(
+                    "failed to decode gateway payload, sending to dead-letter topic: {error}"
+                );
+
+                let dead_letter = DeadLetter {
+                    consumer: String::from("entitycache"),
+                    error: error.to_string(),
+                    event_type: error.event_type,
+                    origin_topic: transport.origin(channel),
+                    shard: error.shard,
+                };
+                if let Err(error) = transport
+                    .publish_dead_letter(&dead_letter, record.key.as_deref(), &record.payload)
+                    .await
+                {
+                    log::error!(
+                        "failed to publish gateway payload with key {} to dead-letter topic: {error:?}",
+                        String::from_utf8_lossy(record.key.as_deref().unwrap_or_default())
+                    );
+                }
+
+                continue;
+            }
+        };
+
+        let InboundPayload {
+            event,
+            event_type,
+            guild_id,
+            shard,
+        } = inbound;
+        log::trace!(
+            "[shard {shard}] received {} event",
+            event_type.as_deref().unwrap_or("UNKNOWN")
+        );
+
+        let transport = transport.clone();
+        dispatcher
+            .dispatch(guild_id, async move {
+                // computed beforehand as the update consumes the event; the invalidations are
+                // published even if the update fails, as it may have partially gone through
+                let invalidations = invalidation::invalidations(&event);
+
+                if let Err(error) = entitycache::update(event).await {
+                    log::error!("[shard {shard}] failed to update cache: {error:?}");
+                }
+
+                for invalidation in invalidations {
+                    let event = InternalEvent::CacheInvalidation(invalidation);
+                    if let Err(error) = transport
+                        .publish_event(Channel::InternalEvent, &event)
+                        .await
+                    {
+                        log::error!(
+                            "[shard {shard}] failed to publish cache invalidation: {error:?}"
+                        );
+                    }
+                }
+            })
+            .await;
+    }
+
+    Ok(())
+}
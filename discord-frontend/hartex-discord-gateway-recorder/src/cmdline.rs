@@ -0,0 +1,40 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Line Handler
+
+use clap::ArgMatches;
+
+use crate::commands;
+
+/// Handle the command line with argument matches.
+pub async fn handle(matches: ArgMatches) -> miette::Result<()> {
+    match matches.subcommand() {
+        Some(("record", subcommand_matches)) => {
+            commands::record::record_command(subcommand_matches).await
+        }
+        Some(("replay", subcommand_matches)) => {
+            commands::replay::replay_command(subcommand_matches).await
+        }
+        _ => Ok(()),
+    }
+}
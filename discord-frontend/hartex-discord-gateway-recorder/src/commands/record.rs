@@ -0,0 +1,103 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Record Command
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::PathBuf;
+use std::pin::pin;
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::signal;
+use hartex_discord_internal_events::inbound::InboundGatewayPayloadKey;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
+use hartex_discord_transport::kafka::KafkaTransport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+use crate::recording;
+use crate::recording::RecordedPayload;
+
+/// The consumer group of the recorder, separate from that of the worker such that recording does
+/// not take payloads away from it.
+const RECORDER_CONSUMER_GROUP_ID: &str = "com.github.teamhartex.hartex.gateway.recorder.consumer";
+
+/// Record the gateway payloads forwarded by the leader to a file.
+///
+/// Recording stops when interrupted, or once the given number of payloads have been recorded.
+#[allow(clippy::module_name_repetitions)]
+pub async fn record_command(matches: &ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let output = matches.get_one::<PathBuf>("output").unwrap();
+    let limit = matches.get_one::<usize>("limit").copied();
+
+    let transport = Transport::Kafka(KafkaTransport::from_env()?);
+    let mut subscription =
+        transport.subscribe(Channel::InboundGatewayPayload, RECORDER_CONSUMER_GROUP_ID)?;
+    let mut writer = BufWriter::new(File::create(output).into_diagnostic()?);
+
+    let mut interrupt = pin!(signal::ctrl_c());
+    let mut recorded = 0;
+    while limit.is_none_or(|limit| recorded < limit) {
+        let record = tokio::select! {
+            record = subscription.next() => record,
+            _ = &mut interrupt => None,
+        };
+        let Some(record) = record else {
+            break;
+        };
+
+        let Some(shard) = InboundGatewayPayloadKey::shard_of(record.key.as_deref()) else {
+            log::warn!("skipping payload with invalid record key");
+
+            continue;
+        };
+        let Ok(payload) = String::from_utf8(record.payload) else {
+            log::warn!("[shard {shard}] skipping payload that is not valid UTF-8");
+
+            continue;
+        };
+
+        let recorded_payload = RecordedPayload {
+            payload,
+            shard,
+            timestamp: recording::now(),
+        };
+        serde_json::to_writer(&mut writer, &recorded_payload).into_diagnostic()?;
+        writer.write_all(b"\n").into_diagnostic()?;
+
+        recorded += 1;
+    }
+
+    writer.flush().into_diagnostic()?;
+
+    println!("{recorded} payload(s) recorded to {}", output.display());
+
+    Ok(())
+}
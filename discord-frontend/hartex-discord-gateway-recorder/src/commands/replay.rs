@@ -0,0 +1,117 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Replay Command
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::signal;
+use hartex_discord_core::tokio::time;
+use hartex_discord_core::tokio::time::Instant;
+use hartex_discord_internal_events::inbound::InboundGatewayPayloadKey;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
+use hartex_discord_transport::inprocess::InProcessTransport;
+use hartex_discord_transport::kafka::KafkaTransport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use miette::miette;
+
+use crate::recording;
+use crate::recording::RecordedPayload;
+
+/// Replay a recording to the worker and the entitycache update service.
+///
+/// Payloads are published to the inbound gateway payload channels with the same delays between
+/// them as when they were recorded, divided by the given speed. With `--in-process`, the worker
+/// and the entitycache update service are run within this process instead of consuming from
+/// Kafka, and keep running after the replay until interrupted.
+#[allow(clippy::large_futures)]
+#[allow(clippy::module_name_repetitions)]
+pub async fn replay_command(matches: &ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    let input = matches.get_one::<PathBuf>("input").unwrap();
+    let speed = *matches.get_one::<f64>("speed").unwrap();
+    if !speed.is_finite() || speed <= 0.0 {
+        return Err(miette!("the replay speed must be a positive number"));
+    }
+
+    let payloads = recording::read(input)?;
+
+    if !matches.get_flag("in-process") {
+        let transport = Transport::Kafka(KafkaTransport::from_env()?);
+        transport.install()?;
+
+        return replay(&transport, &payloads, speed).await;
+    }
+
+    let transport = Transport::InProcess(InProcessTransport::new());
+    transport.install()?;
+
+    tokio::select! {
+        result = async {
+            replay(&transport, &payloads, speed).await?;
+
+            log::info!("replay finished, interrupt to stop the worker and entitycache service");
+            signal::ctrl_c().await.into_diagnostic()
+        } => result,
+        result = hartex_discord_worker::run(transport.clone()) => result,
+        result = hartex_discord_entitycache_service::run(transport.clone()) => result,
+    }
+}
+
+/// Publish the recorded payloads to the inbound gateway payload channels.
+async fn replay(
+    transport: &Transport,
+    payloads: &[RecordedPayload],
+    speed: f64,
+) -> miette::Result<()> {
+    let start = Instant::now();
+    let first_timestamp = payloads.first().map_or(0, |payload| payload.timestamp);
+
+    for payload in payloads {
+        let offset = Duration::from_millis(payload.timestamp - first_timestamp).div_f64(speed);
+        time::sleep_until(start + offset).await;
+
+        let key = InboundGatewayPayloadKey::new(payload.shard)
+            .encode()
+            .into_diagnostic()?;
+        for channel in [
+            Channel::InboundGatewayPayload,
+            Channel::InboundGatewayPayloadCache,
+        ] {
+            transport
+                .publish(channel, Some(&key), payload.payload.as_bytes())
+                .await?;
+        }
+    }
+
+    println!("{} payload(s) replayed", payloads.len());
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Gateway Recorder Executable
+//!
+//! This is the executable for the gateway recorder, which records gateway payloads forwarded by
+//! the leader to a file, and replays recordings to the worker and the entitycache update service.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use std::path::PathBuf;
+
+use clap::Arg;
+use clap::ArgAction;
+use clap::Command;
+use clap::value_parser;
+use hartex_discord_core::tokio;
+
+mod cmdline;
+mod commands;
+mod recording;
+
+/// Recorder entry point.
+#[tokio::main(flavor = "multi_thread")]
+pub async fn main() -> miette::Result<()> {
+    hartex_log::initialize();
+
+    let command = Command::new("gwrec")
+        .subcommand(
+            Command::new("record")
+                .about("Records the gateway payloads forwarded by the leader to a file until interrupted.")
+                .arg(
+                    Arg::new("output")
+                        .required(true)
+                        .action(ArgAction::Set)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("limit")
+                        .long("limit")
+                        .short('l')
+                        .action(ArgAction::Set)
+                        .value_parser(value_parser!(usize)),
+                ),
+        )
+        .subcommand(
+            Command::new("replay")
+                .about("Replays a recording to the worker and the entitycache update service.")
+                .arg(
+                    Arg::new("input")
+                        .required(true)
+                        .action(ArgAction::Set)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("speed")
+                        .long("speed")
+                        .short('s')
+                        .action(ArgAction::Set)
+                        .default_value("1")
+                        .value_parser(value_parser!(f64)),
+                )
+                .arg(
+                    Arg::new("in-process")
+                        .long("in-process")
+                        .num_args(0)
+                        .action(ArgAction::SetTrue),
+                ),
+        );
+
+    let matches = command.get_matches();
+
+    cmdline::handle(matches).await?;
+
+    Ok(())
+}
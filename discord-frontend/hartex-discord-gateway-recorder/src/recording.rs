@@ -0,0 +1,77 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Recording Format
+//!
+//! A recording is a JSON Lines file, each line of which contains a gateway payload, the shard it
+//! was received on and the time it was recorded at.
+
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::path::Path;
+use std::time::SystemTime;
+
+use miette::IntoDiagnostic;
+use miette::miette;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A recorded gateway payload.
+#[derive(Deserialize, Serialize)]
+pub struct RecordedPayload {
+    /// The raw gateway payload, as received from Discord.
+    pub payload: String,
+    /// The shard the payload was received on.
+    pub shard: u32,
+    /// The time the payload was recorded at, in milliseconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// Returns the current time in milliseconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |duration| {
+            u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+        })
+}
+
+/// Reads the recorded payloads of a recording, in the order they were recorded in.
+pub fn read(path: &Path) -> miette::Result<Vec<RecordedPayload>> {
+    let reader = BufReader::new(File::open(path).into_diagnostic()?);
+
+    let mut payloads = reader
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|(index, line)| {
+            let line = line.into_diagnostic()?;
+
+            serde_json::from_str::<RecordedPayload>(&line)
+                .map_err(|error| miette!("malformed recording at line {}: {error}", index + 1))
+        })
+        .collect::<miette::Result<Vec<_>>>()?;
+    payloads.sort_by_key(|payload| payload.timestamp);
+
+    Ok(payloads)
+}
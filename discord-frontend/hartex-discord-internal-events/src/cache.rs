@@ -0,0 +1,61 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Cache Invalidation
+//!
+//! Services keeping cached entities in memory are told to drop them when they may have become
+//! stale.
+
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::EmojiMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The cached entities to invalidate.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "entity")]
+pub enum CacheInvalidation {
+    /// Every cached entity.
+    All,
+    /// A channel or thread, along with the threads in it.
+    Channel { channel_id: Id<ChannelMarker> },
+    /// An emoji.
+    Emoji { emoji_id: Id<EmojiMarker> },
+    /// A guild, along with every entity cached for it.
+    Guild { guild_id: Id<GuildMarker> },
+    /// A member of a guild.
+    Member {
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    },
+    /// A role of a guild.
+    Role {
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+    },
+    /// A user.
+    User { user_id: Id<UserMarker> },
+}
@@ -0,0 +1,74 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Inbound Gateway Payload Keys
+//!
+//! Gateway payloads are forwarded by the leader as they are received from Discord, keyed by the
+//! shard they were received on. The payloads of a shard thereby end up in the same partition and
+//! are consumed in order.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::InternalEventError;
+
+/// The key of a record containing a gateway payload.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct InboundGatewayPayloadKey {
+    /// The shard the payload was received on.
+    pub shard: u32,
+}
+
+impl InboundGatewayPayloadKey {
+    /// Creates the key of a gateway payload received on the given shard.
+    #[must_use]
+    pub fn new(shard: u32) -> Self {
+        Self { shard }
+    }
+
+    /// Decodes a key from the record it was published in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key was encoded with a different schema version, or is not a valid
+    /// key.
+    pub fn decode(key: &[u8]) -> Result<Self, InternalEventError> {
+        crate::decode(key)
+    }
+
+    /// Encodes the key for publishing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key cannot be serialized.
+    pub fn encode(&self) -> Result<Vec<u8>, InternalEventError> {
+        crate::encode(self)
+    }
+
+    /// Returns the shard a record containing a gateway payload was received on, if its key is
+    /// valid.
+    #[must_use]
+    pub fn shard_of(key: Option<&[u8]>) -> Option<u32> {
+        key.and_then(|key| Self::decode(key).ok())
+            .map(|key| key.shard)
+    }
+}
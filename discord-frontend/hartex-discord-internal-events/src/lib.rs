@@ -21,3 +21,193 @@
  */
 
 //! # Payloads for internal events used within HarTex.
+//!
+//! Internal events are exchanged between the services of the bot over the transport. Every event
+//! is encoded as JSON together with the version of the schema it was encoded with, so that a
+//! service can tell apart events it does not understand from malformed ones.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::cache::CacheInvalidation;
+use crate::configuration::ConfigurationChanged;
+use crate::outbound::OutboundGatewayCommandEnvelope;
+use crate::outbound::OutboundTarget;
+use crate::shard::ShardStatus;
+
+pub mod cache;
+pub mod configuration;
+pub mod inbound;
+pub mod outbound;
+pub mod shard;
+
+/// The version of the internal events schema.
+///
+/// This is to be incremented whenever a change is made that older services cannot decode.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// An internal event.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(content = "payload", rename_all = "snake_case", tag = "event")]
+pub enum InternalEvent {
+    /// Cached entities have to be invalidated.
+    CacheInvalidation(CacheInvalidation),
+    /// The configuration of a guild has changed.
+    ConfigurationChanged(ConfigurationChanged),
+    /// A gateway command is to be sent through the shards owned by the leader.
+    OutboundGatewayCommand(OutboundGatewayCommandEnvelope),
+    /// The connection status of a shard has changed.
+    ShardStatus(ShardStatus),
+}
+
+impl InternalEvent {
+    /// Decodes an event from the payload of the record it was published in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload was encoded with a different schema version, or is not a
+    /// valid event.
+    pub fn decode(payload: &[u8]) -> Result<Self, InternalEventError> {
+        decode(payload)
+    }
+
+    /// Encodes the event for publishing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be serialized.
+    pub fn encode(&self) -> Result<Vec<u8>, InternalEventError> {
+        encode(self)
+    }
+
+    /// The name of the event.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::CacheInvalidation(_) => "cache_invalidation",
+            Self::ConfigurationChanged(_) => "configuration_changed",
+            Self::OutboundGatewayCommand(_) => "outbound_gateway_command",
+            Self::ShardStatus(_) => "shard_status",
+        }
+    }
+
+    /// The key of the record the event is published in.
+    ///
+    /// Events are keyed by the guild or shard they concern, such that events concerning the same
+    /// guild or shard are consumed in the order they were published in, while the others are
+    /// spread across partitions.
+    #[must_use]
+    pub fn key(&self) -> String {
+        match self {
+            Self::CacheInvalidation(invalidation) => match invalidation {
+                CacheInvalidation::All => String::from("all"),
+                CacheInvalidation::Channel { channel_id } => format!("channel:{channel_id}"),
+                CacheInvalidation::Emoji { emoji_id } => format!("emoji:{emoji_id}"),
+                CacheInvalidation::Guild { guild_id }
+                | CacheInvalidation::Member { guild_id, .. }
+                | CacheInvalidation::Role { guild_id, .. } => format!("guild:{guild_id}"),
+                CacheInvalidation::User { user_id } => format!("user:{user_id}"),
+            },
+            Self::ConfigurationChanged(changed) => format!("guild:{}", changed.guild_id),
+            Self::OutboundGatewayCommand(envelope) => match envelope.target {
+                OutboundTarget::AllShards => String::from("all"),
+                OutboundTarget::Guild(guild_id) => format!("guild:{guild_id}"),
+                OutboundTarget::Shard(shard_id) => format!("shard:{shard_id}"),
+            },
+            Self::ShardStatus(status) => format!("shard:{}", status.shard_id),
+        }
+    }
+}
+
+/// A value together with the version of the schema it was encoded with.
+#[derive(Deserialize, Serialize)]
+struct Versioned<T> {
+    version: u32,
+    #[serde(flatten)]
+    value: T,
+}
+
+/// The version of the schema a value was encoded with.
+#[derive(Deserialize)]
+struct Version {
+    version: u32,
+}
+
+/// Decodes a versioned value, checking its version before the value itself.
+pub(crate) fn decode<T>(bytes: &[u8]) -> Result<T, InternalEventError>
+where
+    T: DeserializeOwned,
+{
+    let Version { version } = serde_json::from_slice(bytes).map_err(InternalEventError::json)?;
+    if version != SCHEMA_VERSION {
+        return Err(InternalEventError {
+            kind: InternalEventErrorKind::UnsupportedVersion(version),
+        });
+    }
+
+    serde_json::from_slice::<Versioned<T>>(bytes)
+        .map(|versioned| versioned.value)
+        .map_err(InternalEventError::json)
+}
+
+/// Encodes a value with the current schema version.
+pub(crate) fn encode<T>(value: &T) -> Result<Vec<u8>, InternalEventError>
+where
+    T: Serialize,
+{
+    serde_json::to_vec(&Versioned {
+        version: SCHEMA_VERSION,
+        value,
+    })
+    .map_err(InternalEventError::json)
+}
+
+/// Internal event error.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct InternalEventError {
+    /// The error type.
+    pub kind: InternalEventErrorKind,
+}
+
+impl InternalEventError {
+    fn json(error: serde_json::Error) -> Self {
+        Self {
+            kind: InternalEventErrorKind::Json(error),
+        }
+    }
+}
+
+impl Display for InternalEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            InternalEventErrorKind::Json(error) => {
+                write!(f, "failed to encode or decode internal event: {error}")
+            }
+            InternalEventErrorKind::UnsupportedVersion(version) => write!(
+                f,
+                "internal event was encoded with schema version {version}, expected {SCHEMA_VERSION}"
+            ),
+        }
+    }
+}
+
+impl Error for InternalEventError {}
+
+/// The type of internal event error that has occurred.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub enum InternalEventErrorKind {
+    Json(serde_json::Error),
+    UnsupportedVersion(u32),
+}
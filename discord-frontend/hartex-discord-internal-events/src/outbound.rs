@@ -0,0 +1,103 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Outbound Gateway Commands
+//!
+//! Gateway commands are sent through the shards owned by the leader. Any service may request a
+//! gateway command to be sent by publishing an envelope as an internal event to the outbound
+//! communication topic.
+
+use hartex_discord_core::discord::model::gateway::payload::outgoing::RequestGuildMembers;
+use hartex_discord_core::discord::model::gateway::payload::outgoing::UpdateVoiceState;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_utils::presence::Presence;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A gateway command, together with the shards it is to be sent through.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OutboundGatewayCommandEnvelope {
+    /// The gateway command to send.
+    pub command: OutboundGatewayCommand,
+    /// The shards to send the command through.
+    pub target: OutboundTarget,
+}
+
+impl OutboundGatewayCommandEnvelope {
+    /// Creates an envelope sending a gateway command through the given shards.
+    #[must_use]
+    pub fn new(command: OutboundGatewayCommand, target: OutboundTarget) -> Self {
+        Self { command, target }
+    }
+}
+
+/// A gateway command that can be sent through a shard.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(content = "payload", rename_all = "snake_case", tag = "command")]
+pub enum OutboundGatewayCommand {
+    /// Request the members of a guild, either all of them or the given users.
+    RequestGuildMembers(RequestGuildMembers),
+    /// Update the presence of the bot. The shard placeholder in the activity name is replaced
+    /// with the number of each shard it is sent through.
+    UpdatePresence(Presence),
+    /// Join, move between or leave voice channels.
+    UpdateVoiceState(UpdateVoiceState),
+}
+
+/// The shards a gateway command is to be sent through.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(content = "id", rename_all = "snake_case", tag = "kind")]
+pub enum OutboundTarget {
+    /// Every shard.
+    AllShards,
+    /// The shard receiving the events of the given guild.
+    Guild(Id<GuildMarker>),
+    /// The shard with the given number.
+    Shard(u32),
+}
+
+impl OutboundTarget {
+    /// Returns the numbers of the targeted shards, given the total number of shards.
+    ///
+    /// No shards are targeted by guild if the total number of shards is zero.
+    #[must_use]
+    pub fn shards(&self, shard_total: u32) -> Vec<u32> {
+        match self {
+            Self::AllShards => (0..shard_total).collect(),
+            Self::Guild(guild_id) => shard_of_guild(*guild_id, shard_total).into_iter().collect(),
+            Self::Shard(shard_id) => vec![*shard_id],
+        }
+    }
+}
+
+/// Computes the number of the shard receiving the events of a guild.
+///
+/// This is the formula documented by Discord: `(guild_id >> 22) % shard_total`. There is no such
+/// shard if the total number of shards is zero.
+#[allow(clippy::cast_possible_truncation)]
+#[must_use]
+pub fn shard_of_guild(guild_id: Id<GuildMarker>, shard_total: u32) -> Option<u32> {
+    (guild_id.get() >> 22)
+        .checked_rem(u64::from(shard_total))
+        .map(|shard| shard as u32)
+}
@@ -0,0 +1,50 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Shard Status
+//!
+//! The gateway connections are owned by the leader, which reports on them to the other services.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The connection status of a shard.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ShardStatus {
+    /// The number of the shard.
+    pub shard_id: u32,
+    /// The status of the shard.
+    pub status: ShardStatusKind,
+}
+
+/// The connection status of a shard.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum ShardStatusKind {
+    /// The gateway connection has been closed, with the given close code if there is one.
+    Disconnected { close_code: Option<u16> },
+    /// The shard has identified and started a new session.
+    Ready,
+    /// The shard has resumed its previous session.
+    Resumed,
+}
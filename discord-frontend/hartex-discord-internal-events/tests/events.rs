@@ -0,0 +1,152 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_core::discord::model::gateway::presence::Status;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_discord_internal_events::InternalEventErrorKind;
+use hartex_discord_internal_events::cache::CacheInvalidation;
+use hartex_discord_internal_events::configuration::ConfigurationChanged;
+use hartex_discord_internal_events::outbound::OutboundGatewayCommand;
+use hartex_discord_internal_events::outbound::OutboundGatewayCommandEnvelope;
+use hartex_discord_internal_events::outbound::OutboundTarget;
+use hartex_discord_internal_events::shard::ShardStatus;
+use hartex_discord_internal_events::shard::ShardStatusKind;
+use hartex_discord_utils::presence::Presence;
+
+fn round_trip(event: &InternalEvent) -> InternalEvent {
+    InternalEvent::decode(&event.encode().unwrap()).unwrap()
+}
+
+#[test]
+fn cache_invalidation_round_trips() {
+    let invalidation = CacheInvalidation::Member {
+        guild_id: Id::new(1234),
+        user_id: Id::new(5678),
+    };
+
+    let InternalEvent::CacheInvalidation(decoded) =
+        round_trip(&InternalEvent::CacheInvalidation(invalidation))
+    else {
+        panic!("expected a cache invalidation");
+    };
+    assert_eq!(decoded, invalidation);
+}
+
+#[test]
+fn configuration_changed_round_trips() {
+    let changed = ConfigurationChanged {
+        guild_id: Id::new(1234),
+    };
+
+    let InternalEvent::ConfigurationChanged(decoded) =
+        round_trip(&InternalEvent::ConfigurationChanged(changed))
+    else {
+        panic!("expected a configuration change");
+    };
+    assert_eq!(decoded, changed);
+}
+
+#[test]
+fn outbound_gateway_command_round_trips() {
+    let event = InternalEvent::OutboundGatewayCommand(OutboundGatewayCommandEnvelope::new(
+        OutboundGatewayCommand::UpdatePresence(Presence {
+            activity: None,
+            status: Status::Idle,
+        }),
+        OutboundTarget::Shard(3),
+    ));
+
+    let decoded = round_trip(&event);
+    assert!(matches!(
+        decoded,
+        InternalEvent::OutboundGatewayCommand(OutboundGatewayCommandEnvelope {
+            command: OutboundGatewayCommand::UpdatePresence(Presence {
+                activity: None,
+                status: Status::Idle,
+            }),
+            target: OutboundTarget::Shard(3),
+        })
+    ));
+}
+
+#[test]
+fn shard_status_round_trips() {
+    for status in [
+        ShardStatusKind::Disconnected {
+            close_code: Some(4000),
+        },
+        ShardStatusKind::Disconnected { close_code: None },
+        ShardStatusKind::Ready,
+        ShardStatusKind::Resumed,
+    ] {
+        let status = ShardStatus {
+            shard_id: 300,
+            status,
+        };
+
+        let InternalEvent::ShardStatus(decoded) = round_trip(&InternalEvent::ShardStatus(status))
+        else {
+            panic!("expected a shard status");
+        };
+        assert_eq!(decoded, status);
+    }
+}
+
+#[test]
+fn event_with_other_schema_version_is_rejected() {
+    let error = InternalEvent::decode(
+        br#"{"version":0,"event":"shard_status","payload":{"shard_id":0,"status":{"kind":"ready"}}}"#,
+    )
+    .unwrap_err();
+
+    assert!(matches!(
+        error.kind,
+        InternalEventErrorKind::UnsupportedVersion(0)
+    ));
+}
+
+#[test]
+fn events_are_keyed_by_guild_or_shard() {
+    let guild_id = Id::new(1234);
+
+    assert_eq!(
+        InternalEvent::ConfigurationChanged(ConfigurationChanged { guild_id }).key(),
+        "guild:1234"
+    );
+    assert_eq!(
+        InternalEvent::CacheInvalidation(CacheInvalidation::Role {
+            guild_id,
+            role_id: Id::new(5678),
+        })
+        .key(),
+        "guild:1234"
+    );
+    assert_eq!(
+        InternalEvent::ShardStatus(ShardStatus {
+            shard_id: 300,
+            status: ShardStatusKind::Ready,
+        })
+        .key(),
+        "shard:300"
+    );
+}
@@ -0,0 +1,218 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Gateway Traffic
+//!
+//! Forwarding of gateway traffic between the shards and the transport.
+
+use std::collections::HashMap;
+
+use futures_util::StreamExt as FutureStreamExt;
+use hartex_discord_core::discord::gateway::CloseFrame;
+use hartex_discord_core::discord::gateway::Message as GatewayMessage;
+use hartex_discord_core::discord::gateway::MessageSender;
+use hartex_discord_core::discord::gateway::Shard;
+use hartex_discord_core::discord::gateway::error::ChannelError;
+use hartex_discord_core::discord::gateway::queue::Queue;
+use hartex_discord_core::discord::model::gateway::event::GatewayEventDeserializer;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_discord_internal_events::inbound::InboundGatewayPayloadKey;
+use hartex_discord_internal_events::outbound::OutboundGatewayCommand;
+use hartex_discord_internal_events::shard::ShardStatus;
+use hartex_discord_internal_events::shard::ShardStatusKind;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Subscription;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use miette::miette;
+
+use crate::latency;
+use crate::session::CloseOutcome;
+use crate::session::SessionTracker;
+
+/// Handle inbound traffic of a shard.
+pub async fn inbound<Q>(
+    shard: &mut Shard<Q>,
+    tracker: &mut SessionTracker,
+    transport: Transport,
+) -> miette::Result<()>
+where
+    Q: Queue + Send + Sync + Sized + Unpin + 'static,
+{
+    let shard_id = shard.id().number();
+    let key = InboundGatewayPayloadKey::new(shard_id)
+        .encode()
+        .into_diagnostic()?;
+
+    let mut published_heartbeats = 0;
+    while let Some(result) = shard.next().await {
+        match result {
+            Ok(message) => {
+                latency::publish_if_updated(shard, &mut published_heartbeats);
+
+                let bytes = match message {
+                    GatewayMessage::Close(frame) => {
+                        let close_code = frame.as_ref().map(CloseFrame::code);
+                        publish_status(
+                            &transport,
+                            shard_id,
+                            ShardStatusKind::Disconnected { close_code },
+                        )
+                        .await;
+                        handle_close(shard_id, frame.as_ref())?;
+
+                        continue;
+                    }
+                    GatewayMessage::Text(string) => {
+                        tracker.track(&string);
+                        if let Some(status) = status_of(&string) {
+                            publish_status(&transport, shard_id, status).await;
+                        }
+
+                        string.into_bytes()
+                    }
+                };
+
+                log::trace!("[shard {shard_id}] received binary payload from gateway");
+
+                // send payload to worker process
+                if let Err(error) = transport
+                    .publish(Channel::InboundGatewayPayload, Some(&key), &bytes)
+                    .await
+                {
+                    log::error!("failed to forward payload to worker: {error:?}");
+
+                    continue;
+                }
+
+                // send payload to caching process
+                if let Err(error) = transport
+                    .publish(Channel::InboundGatewayPayloadCache, Some(&key), &bytes)
+                    .await
+                {
+                    log::error!("failed to forward payload to entitycache: {error:?}");
+                }
+            }
+            Err(error) => {
+                log::warn!("[shard {shard_id}] error when receiving gateway message: {error}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the connection status of a shard a gateway payload indicates, if any.
+fn status_of(payload: &str) -> Option<ShardStatusKind> {
+    match GatewayEventDeserializer::from_json(payload)?.event_type() {
+        Some("READY") => Some(ShardStatusKind::Ready),
+        Some("RESUMED") => Some(ShardStatusKind::Resumed),
+        _ => None,
+    }
+}
+
+/// Publish a change in the connection status of a shard.
+async fn publish_status(transport: &Transport, shard_id: u32, status: ShardStatusKind) {
+    let event = InternalEvent::ShardStatus(ShardStatus { shard_id, status });
+    if let Err(error) = transport
+        .publish_event(Channel::InternalEvent, &event)
+        .await
+    {
+        log::warn!("[shard {shard_id}] failed to publish shard status: {error:?}");
+    }
+}
+
+/// Handle a close frame received by a shard.
+///
+/// The shard reconnects by itself unless the close code is fatal, in which case an error is
+/// returned.
+fn handle_close(shard_id: u32, frame: Option<&CloseFrame<'_>>) -> miette::Result<()> {
+    let code = frame.map(CloseFrame::code);
+    match CloseOutcome::of(frame) {
+        CloseOutcome::Fatal => {
+            return Err(miette!(
+                "[shard {shard_id}] gateway connection closed with fatal close code {code:?}"
+            ));
+        }
+        CloseOutcome::Reidentify => log::warn!(
+            "[shard {shard_id}] gateway session invalidated with close code {code:?}, identifying again"
+        ),
+        CloseOutcome::Resume => log::info!(
+            "[shard {shard_id}] gateway connection closed with close code {code:?}, resuming"
+        ),
+    }
+
+    Ok(())
+}
+
+/// Handle outbound traffic, forwarding gateway commands to the shards they are meant for.
+pub async fn outbound(
+    senders: HashMap<u32, MessageSender>,
+    shard_total: u32,
+    mut subscription: Subscription,
+) -> miette::Result<()> {
+    while let Some(record) = subscription.next().await {
+        let envelope = match InternalEvent::decode(&record.payload) {
+            Ok(InternalEvent::OutboundGatewayCommand(envelope)) => envelope,
+            Ok(event) => {
+                log::trace!("skipping {} internal event", event.name());
+
+                continue;
+            }
+            Err(error) => {
+                log::warn!("skipping invalid outbound gateway command: {error}");
+
+                continue;
+            }
+        };
+
+        for shard_id in envelope.target.shards(shard_total) {
+            let Some(sender) = senders.get(&shard_id) else {
+                log::trace!("[shard {shard_id}] shard is not managed by this leader, skipping");
+
+                continue;
+            };
+
+            if let Err(error) = send(sender, shard_id, &envelope.command) {
+                log::warn!("[shard {shard_id}] failed to send gateway command: {error}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Send a gateway command through a shard.
+fn send(
+    sender: &MessageSender,
+    shard_id: u32,
+    command: &OutboundGatewayCommand,
+) -> Result<(), ChannelError> {
+    match command {
+        OutboundGatewayCommand::RequestGuildMembers(request) => sender.command(request),
+        OutboundGatewayCommand::UpdatePresence(presence) => {
+            sender.command(&presence.command(shard_id))
+        }
+        OutboundGatewayCommand::UpdateVoiceState(update) => sender.command(update),
+    }
+}
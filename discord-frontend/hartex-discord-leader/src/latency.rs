@@ -0,0 +1,72 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Shard Latency
+//!
+//! Heartbeat latencies are only known to the leader, which owns the gateway connections. They are
+//! published to the database so that workers can report them.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use hartex_database_queries::queries::discord_frontend::shard_latency_upsert::ShardLatencyUpsert;
+use hartex_discord_core::discord::gateway::Shard;
+use hartex_discord_core::discord::gateway::queue::Queue;
+use hartex_discord_core::tokio::task::spawn;
+use hartex_log::log;
+
+/// Publish the most recent heartbeat latency of a shard, if a heartbeat has been acknowledged
+/// since the last time it was published.
+pub fn publish_if_updated<Q>(shard: &Shard<Q>, published_heartbeats: &mut u32)
+where
+    Q: Queue,
+{
+    let latency = shard.latency();
+    if latency.heartbeats() == *published_heartbeats {
+        return;
+    }
+
+    *published_heartbeats = latency.heartbeats();
+    let Some(recent) = latency.recent().first().copied() else {
+        return;
+    };
+
+    let shard_id = shard.id().number();
+    spawn(async move {
+        if let Err(error) = publish(shard_id, recent).await {
+            log::warn!("[shard {shard_id}] failed to publish shard latency: {error:?}");
+        }
+    });
+}
+
+/// Upsert the latency of a shard into the database.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_wrap)]
+async fn publish(shard_id: u32, latency: Duration) -> hartex_database_queries::result::Result<()> {
+    ShardLatencyUpsert::bind(shard_id as i32, latency.as_millis() as i64, Utc::now())
+        .executor()
+        .await?
+        .execute()
+        .await?;
+
+    Ok(())
+}
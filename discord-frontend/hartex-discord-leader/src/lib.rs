@@ -0,0 +1,145 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Leader
+//!
+//! The leader connects to the Discord API, receives events and forwards them to the workers. It
+//! runs either as its own process, or together with the other components in a single process.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+// the commands crate is not otherwise referenced, but needs to be linked for its commands to be
+// added to the command registry the gateway intents are derived from
+extern crate hartex_discord_commands;
+
+use std::collections::HashMap;
+
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::sync::watch;
+use hartex_discord_core::tokio::task::JoinSet;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+use crate::session::SessionTracker;
+use crate::shards::ShardAssignment;
+
+mod gateway;
+mod latency;
+mod queue;
+mod session;
+mod shards;
+
+/// The consumer group of the leader consuming outbound gateway commands.
+const OUTBOUND_CONSUMER_GROUP_ID: &str =
+    "com.github.teamhartex.hartex.inbound.gateway.command.consumer";
+
+/// Run the leader until the shutdown future completes.
+///
+/// # Errors
+///
+/// Returns an error if the leader cannot be started.
+pub async fn run<F>(transport: Transport, shutdown: F) -> miette::Result<()>
+where
+    F: Future<Output = ()>,
+{
+    // every leader needs to see every outbound gateway command, so leaders managing a range of
+    // shards each consume in their own group
+    let assignment = ShardAssignment::from_env()?;
+    let group_id = match &assignment {
+        ShardAssignment::All => String::from(OUTBOUND_CONSUMER_GROUP_ID),
+        ShardAssignment::Range { range, .. } => format!(
+            "{OUTBOUND_CONSUMER_GROUP_ID}.shards.{start}-{end}",
+            start = range.start,
+            end = range.end
+        ),
+    };
+    let subscription = transport.subscribe(Channel::OutboundCommunication, &group_id)?;
+
+    log::trace!("building clusters");
+    let queue = queue::obtain()?;
+    let sessions = session::load().await.unwrap_or_else(|error| {
+        log::warn!("failed to load persisted gateway sessions, identifying all shards: {error:?}");
+        HashMap::new()
+    });
+    let shards = shards::obtain(queue, &assignment, &sessions).await?;
+
+    let (tx, rx) = watch::channel(false);
+
+    let shard_total = shards.first().map_or(0, |shard| shard.id().total());
+    let senders = shards
+        .iter()
+        .map(|shard| (shard.id().number(), shard.sender()))
+        .collect::<HashMap<_, _>>();
+
+    let mut set = JoinSet::new();
+    let mut outbound_rx = rx.clone();
+    set.spawn(async move {
+        tokio::select! {
+            result = gateway::outbound(senders, shard_total, subscription) => {
+                if let Err(error) = result {
+                    log::error!("stopped forwarding outbound gateway commands: {error:?}");
+                }
+            },
+            _ = outbound_rx.changed() => {}
+        }
+    });
+
+    log::trace!("launching {} shard(s)", shards.len());
+    for mut shard in shards {
+        let mut rx = rx.clone();
+        let transport = transport.clone();
+        let mut tracker = SessionTracker::new(
+            sessions
+                .get(&shard.id().number())
+                .map(|persisted| persisted.resume_url.clone()),
+        );
+
+        set.spawn(async move {
+            tokio::select! {
+                result = gateway::inbound(&mut shard, &mut tracker, transport) => {
+                    if let Err(error) = result {
+                        log::error!(
+                            "[shard {shard_id}] shard stopped: {error:?}",
+                            shard_id = shard.id().number()
+                        );
+                    }
+                },
+                _ = rx.changed() => {
+                    session::close_and_persist(&mut shard, &tracker).await;
+                }
+            }
+        });
+    }
+
+    shutdown.await;
+
+    tx.send(true).into_diagnostic()?;
+
+    // wait for all tasks to complete
+    while set.join_next().await.is_some() {}
+
+    Ok(())
+}
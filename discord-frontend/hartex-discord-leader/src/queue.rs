@@ -33,6 +33,12 @@ use hartex_discord_core::tokio::sync::oneshot::Sender;
 use hartex_discord_core::tokio::time::sleep;
 use hartex_log::log;
 use miette::IntoDiagnostic;
+use miette::miette;
+use tokio_postgres::Client;
+use tokio_postgres::NoTls;
+
+/// The first key of the advisory locks guarding the identify buckets, the second being the bucket.
+const IDENTIFY_LOCK_CLASS: i32 = 0x4854_4944;
 
 /// A discriminated union of supported bot queues.
 #[allow(clippy::module_name_repetitions)]
@@ -42,6 +48,8 @@ pub enum BotQueue {
     Local(LocalQueue),
     /// A large bot queue.
     LargeBot(LargeBotQueue),
+    /// A queue shared between leader processes.
+    Postgres(PostgresQueue),
 }
 
 impl Queue for BotQueue {
@@ -49,6 +57,7 @@ impl Queue for BotQueue {
         match self {
             Self::Local(local) => local.enqueue(id),
             Self::LargeBot(large_bot) => large_bot.enqueue(id),
+            Self::Postgres(postgres) => postgres.enqueue(id),
         }
     }
 }
@@ -114,6 +123,45 @@ impl Queue for LargeBotQueue {
     }
 }
 
+/// A queue shared between leader processes, coordinated through Postgres advisory locks.
+///
+/// Each bucket is guarded by an advisory lock that is held for the wait duration after a shard is
+/// allowed to identify, so that at most one shard per bucket identifies within that duration
+/// across every leader connected to the same database.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct PostgresQueue(Vec<UnboundedSender<Sender<()>>>);
+
+impl PostgresQueue {
+    /// Create a queue shared through the given database.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn new(url: &str, buckets: usize, duration: Duration) -> Self {
+        let mut queues = Vec::with_capacity(buckets);
+        for bucket in 0..buckets {
+            let (tx, rx) = unbounded_channel();
+            tokio::spawn(wait_for_lock(rx, url.to_string(), bucket as i32, duration));
+            queues.push(tx);
+        }
+
+        Self(queues)
+    }
+}
+
+impl Queue for PostgresQueue {
+    #[allow(unused_must_use)]
+    fn enqueue(&'_ self, shard_id: u32) -> Receiver<()> {
+        #[allow(clippy::cast_possible_truncation)]
+        let bucket = (shard_id % (self.0.len() as u32)) as usize;
+        let (tx, rx) = oneshot::channel();
+        if let Err(error) = self.0[bucket].clone().send(tx) {
+            log::warn!("skipping, send failed: {:?}", error);
+        }
+
+        rx
+    }
+}
+
 /// Waits for a while until an unbounded receiver receives something.
 async fn wait_for_while(mut rx: UnboundedReceiver<Sender<()>>, duration: Duration) {
     while let Some(tx) = rx.recv().await {
@@ -125,6 +173,67 @@ async fn wait_for_while(mut rx: UnboundedReceiver<Sender<()>>, duration: Duratio
     }
 }
 
+/// Waits for the advisory lock of a bucket each time an unbounded receiver receives something.
+async fn wait_for_lock(
+    mut rx: UnboundedReceiver<Sender<()>>,
+    url: String,
+    bucket: i32,
+    duration: Duration,
+) {
+    let mut client = None;
+    while let Some(tx) = rx.recv().await {
+        while let Err(error) = advisory_lock(&mut client, &url, "pg_advisory_lock", bucket).await {
+            log::warn!("[bucket {bucket}] failed to acquire identify lock, retrying: {error}");
+            sleep(duration).await;
+        }
+
+        if let Err(error) = tx.send(()) {
+            log::warn!("skipping, send failed: {:?}", error);
+        }
+
+        sleep(duration).await;
+
+        // the lock is released with the connection if it cannot be released explicitly
+        if let Err(error) = advisory_lock(&mut client, &url, "pg_advisory_unlock", bucket).await {
+            log::warn!("[bucket {bucket}] failed to release identify lock: {error}");
+        }
+    }
+}
+
+/// Calls an advisory lock function for a bucket, connecting to the database if necessary.
+///
+/// The connection is dropped on failure, which also releases any lock held through it.
+async fn advisory_lock(
+    client: &mut Option<Client>,
+    url: &str,
+    function: &str,
+    bucket: i32,
+) -> Result<(), tokio_postgres::Error> {
+    let connected = match client.take() {
+        Some(connected) => connected,
+        None => {
+            let (connected, connection) = tokio_postgres::connect(url, NoTls).await?;
+            tokio::spawn(async move {
+                if let Err(error) = connection.await {
+                    log::warn!("identify lock connection closed: {error}");
+                }
+            });
+
+            connected
+        }
+    };
+
+    connected
+        .execute(
+            &format!("SELECT {function}($1, $2)"),
+            &[&IDENTIFY_LOCK_CLASS, &bucket],
+        )
+        .await?;
+    client.replace(connected);
+
+    Ok(())
+}
+
 /// Obtain a queue to use for the startup of the bot.
 pub fn obtain() -> miette::Result<BotQueue> {
     let concurrency = std::env::var("SHARD_CONCURRENCY")
@@ -138,7 +247,16 @@ pub fn obtain() -> miette::Result<BotQueue> {
             .into_diagnostic()?,
     );
 
-    Ok(if concurrency == 1 {
+    let shared = match std::env::var("SHARD_IDENTIFY_QUEUE").as_deref() {
+        Ok("postgres") => true,
+        Ok("local" | "") | Err(_) => false,
+        Ok(other) => return Err(miette!("unknown shard identify queue: {other}")),
+    };
+
+    Ok(if shared {
+        let url = std::env::var("DISCORD_FRONTEND_PGSQL_URL").into_diagnostic()?;
+        BotQueue::Postgres(PostgresQueue::new(&url, concurrency, wait))
+    } else if concurrency == 1 {
         BotQueue::Local(LocalQueue::new(wait))
     } else {
         BotQueue::LargeBot(LargeBotQueue::new(concurrency, wait))
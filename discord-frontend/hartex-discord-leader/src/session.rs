@@ -0,0 +1,229 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Gateway Sessions
+//!
+//! The session of each shard is persisted to the database when the leader shuts down, so that
+//! shards can resume their sessions after a restart instead of identifying again.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::TimeDelta;
+use chrono::Utc;
+use futures_util::StreamExt;
+use hartex_database_queries::queries::discord_frontend::gateway_session_select_updated_after::GatewaySessionSelectUpdatedAfter;
+use hartex_database_queries::queries::discord_frontend::gateway_session_upsert::GatewaySessionUpsert;
+use hartex_discord_core::discord::gateway::CloseFrame;
+use hartex_discord_core::discord::gateway::Message as GatewayMessage;
+use hartex_discord_core::discord::gateway::Session;
+use hartex_discord_core::discord::gateway::Shard;
+use hartex_discord_core::discord::gateway::ShardId;
+use hartex_discord_core::discord::gateway::queue::Queue;
+use hartex_discord_core::discord::model::gateway::CloseCode;
+use hartex_discord_core::discord::model::gateway::event::GatewayEventDeserializer;
+use hartex_discord_core::tokio::time::timeout;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use serde::Deserialize;
+
+/// How long a persisted session is considered resumable.
+///
+/// Discord does not document how long a session stays resumable; a session that can no longer be
+/// resumed is invalidated by Discord and the shard identifies again.
+const RESUMABLE_FOR_SECS: i64 = 300;
+
+/// How long to wait for the close frame to be sent when shutting down a shard.
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What a close frame means for the session of a shard.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CloseOutcome {
+    /// The connection cannot be reestablished; the shard must not reconnect.
+    Fatal,
+    /// The session has been invalidated; the shard reconnects and identifies again.
+    Reidentify,
+    /// The session is still valid; the shard reconnects and resumes it.
+    Resume,
+}
+
+impl CloseOutcome {
+    /// Classify a close frame by its close code.
+    pub fn of(frame: Option<&CloseFrame<'_>>) -> Self {
+        let Some(frame) = frame else {
+            return Self::Resume;
+        };
+
+        match frame.code() {
+            // closing with these codes invalidates the session
+            1000 | 1001 => Self::Reidentify,
+            code => match CloseCode::try_from(code) {
+                Ok(CloseCode::InvalidSequence | CloseCode::SessionTimedOut) => Self::Reidentify,
+                Ok(close_code) if !close_code.can_reconnect() => Self::Fatal,
+                _ => Self::Resume,
+            },
+        }
+    }
+}
+
+/// A gateway session persisted from a previous run of the leader.
+#[derive(Clone, Debug)]
+pub struct PersistedSession {
+    /// The URL to connect to when resuming the session.
+    pub resume_url: String,
+    /// The session itself.
+    pub session: Session,
+    /// The total number of shards when the session was persisted.
+    pub shard_total: u32,
+}
+
+/// Tracks the parts of the session of a shard that the shard does not expose.
+#[derive(Debug, Default)]
+pub struct SessionTracker {
+    resume_url: Option<String>,
+}
+
+impl SessionTracker {
+    /// Create a tracker, starting from a previously persisted resume URL if there is one.
+    pub fn new(resume_url: Option<String>) -> Self {
+        Self { resume_url }
+    }
+
+    /// Track the resume URL of the session if the payload is a `READY` event.
+    pub fn track(&mut self, payload: &str) {
+        let Some(deserializer) = GatewayEventDeserializer::from_json(payload) else {
+            return;
+        };
+
+        if deserializer.event_type() != Some("READY") {
+            return;
+        }
+
+        match serde_json::from_str::<ReadyPayload>(payload) {
+            Ok(ready) => self.resume_url = Some(ready.d.resume_gateway_url),
+            Err(error) => log::warn!("failed to read resume url from ready payload: {error}"),
+        }
+    }
+
+    /// Persist the given session of a shard, if there is one.
+    #[allow(clippy::cast_possible_wrap)]
+    pub async fn persist(
+        &self,
+        shard_id: ShardId,
+        session: Option<Session>,
+    ) -> hartex_database_queries::result::Result<()> {
+        let (Some(session), Some(resume_url)) = (session, self.resume_url.clone()) else {
+            return Ok(());
+        };
+
+        GatewaySessionUpsert::bind(
+            shard_id.number() as i32,
+            shard_id.total() as i32,
+            session.id().to_string(),
+            resume_url,
+            session.sequence() as i64,
+            Utc::now(),
+        )
+        .executor()
+        .await?
+        .execute()
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// The parts of a `READY` payload needed to resume its session.
+#[derive(Deserialize)]
+struct ReadyPayload {
+    d: ReadyData,
+}
+
+/// The data of a `READY` payload.
+#[derive(Deserialize)]
+struct ReadyData {
+    resume_gateway_url: String,
+}
+
+/// Load the sessions persisted recently enough to be resumed, keyed by shard number.
+#[allow(clippy::cast_sign_loss)]
+pub async fn load() -> miette::Result<HashMap<u32, PersistedSession>> {
+    let updated_after = Utc::now() - TimeDelta::seconds(RESUMABLE_FOR_SECS);
+    let sessions = GatewaySessionSelectUpdatedAfter::bind(updated_after)
+        .executor()
+        .await
+        .into_diagnostic()?
+        .many()
+        .await
+        .into_diagnostic()?;
+
+    Ok(sessions
+        .into_iter()
+        .map(|session| {
+            (
+                session.shard_id() as u32,
+                PersistedSession {
+                    resume_url: session.resume_url().to_string(),
+                    session: Session::new(
+                        session.sequence() as u64,
+                        session.session_id().to_string(),
+                    ),
+                    shard_total: session.shard_total() as u32,
+                },
+            )
+        })
+        .collect())
+}
+
+/// Close a shard such that its session stays resumable, and persist the session.
+pub async fn close_and_persist<Q>(shard: &mut Shard<Q>, tracker: &SessionTracker)
+where
+    Q: Queue + Send + Sync + Sized + Unpin + 'static,
+{
+    let shard_id = shard.id();
+
+    // events received after this point are not forwarded, but they are replayed upon resuming
+    let session = shard.session().cloned();
+    shard.close(CloseFrame::RESUME);
+
+    let closed = timeout(CLOSE_TIMEOUT, async {
+        while let Some(result) = shard.next().await {
+            if matches!(result, Ok(GatewayMessage::Close(_))) {
+                break;
+            }
+        }
+    })
+    .await;
+    if closed.is_err() {
+        log::warn!(
+            "[shard {shard_id}] timed out waiting for the shard to close",
+            shard_id = shard_id.number()
+        );
+    }
+
+    if let Err(error) = tracker.persist(shard_id, session).await {
+        log::warn!(
+            "[shard {shard_id}] failed to persist gateway session: {error:?}",
+            shard_id = shard_id.number()
+        );
+    }
+}
@@ -20,64 +20,216 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::collections::HashMap;
+use std::env;
 use std::ops::Deref;
+use std::ops::Range;
 
+use hartex_discord_commands_core::registry;
 use hartex_discord_core::discord::gateway::ConfigBuilder;
 use hartex_discord_core::discord::gateway::Intents;
 use hartex_discord_core::discord::gateway::Shard;
 use hartex_discord_core::discord::gateway::ShardId;
+use hartex_discord_core::discord::gateway::create_range;
 use hartex_discord_core::discord::gateway::create_recommended;
 use hartex_discord_core::discord::gateway::queue::Queue;
-use hartex_discord_core::discord::model::gateway::payload::outgoing::update_presence::UpdatePresencePayload;
-use hartex_discord_core::discord::model::gateway::presence::Activity;
-use hartex_discord_core::discord::model::gateway::presence::ActivityType;
-use hartex_discord_core::discord::model::gateway::presence::Status;
 use hartex_discord_utils::CLIENT;
 use hartex_discord_utils::TOKEN;
+use hartex_discord_utils::presence::Presence;
+use hartex_log::log;
 use miette::IntoDiagnostic;
+use miette::miette;
+
+use crate::session::PersistedSession;
+
+/// The shards managed by a leader.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ShardAssignment {
+    /// Every shard, with the total number of shards recommended by Discord.
+    All,
+    /// A range of shards, out of the given total number of shards.
+    Range {
+        /// The range of shard numbers.
+        range: Range<u32>,
+        /// The total number of shards across all leaders.
+        total: u32,
+    },
+}
+
+impl ShardAssignment {
+    /// Read the shard assignment of this leader from the environment.
+    ///
+    /// Without `SHARD_TOTAL`, the leader manages every shard. Otherwise, it manages either the
+    /// shards in `SHARD_RANGE`, written as `start..end`, or an even slice of the shards determined
+    /// by `LEADER_CLUSTER_INDEX` out of `LEADER_CLUSTER_COUNT` leaders.
+    pub fn from_env() -> miette::Result<Self> {
+        let Some(total) = non_empty_var("SHARD_TOTAL") else {
+            return Ok(Self::All);
+        };
+        let total = total.parse::<u32>().into_diagnostic()?;
+
+        let range = if let Some(range) = non_empty_var("SHARD_RANGE") {
+            let (start, end) = range
+                .split_once("..")
+                .ok_or_else(|| miette!("shard range must be written as start..end: {range}"))?;
+
+            start.trim().parse::<u32>().into_diagnostic()?
+                ..end.trim().parse::<u32>().into_diagnostic()?
+        } else {
+            let index = non_empty_var("LEADER_CLUSTER_INDEX")
+                .ok_or_else(|| miette!("either SHARD_RANGE or LEADER_CLUSTER_INDEX must be set"))?
+                .parse::<u32>()
+                .into_diagnostic()?;
+            let count = non_empty_var("LEADER_CLUSTER_COUNT")
+                .ok_or_else(|| miette!("LEADER_CLUSTER_COUNT must be set"))?
+                .parse::<u32>()
+                .into_diagnostic()?;
+            if index >= count {
+                return Err(miette!(
+                    "cluster index {index} is out of bounds for {count} cluster(s)"
+                ));
+            }
+
+            cluster_range(index, count, total)
+        };
+
+        if range.is_empty() || range.end > total {
+            return Err(miette!(
+                "shard range {range:?} is empty or out of bounds for {total} shard(s)"
+            ));
+        }
+
+        Ok(Self::Range { range, total })
+    }
+}
+
+/// Computes the slice of the shards managed by a cluster.
+///
+/// Every cluster manages `total / count` shards, with the remaining shards spread over the first
+/// clusters, such that the sizes of the slices differ by at most one.
+fn cluster_range(index: u32, count: u32, total: u32) -> Range<u32> {
+    let per_cluster = total / count;
+    let remainder = total % count;
+    let start = index * per_cluster + index.min(remainder);
+    let end = start + per_cluster + u32::from(index < remainder);
+
+    start..end
+}
 
 /// Obtain a list of shards.
-pub async fn obtain<Q>(queue: Q) -> miette::Result<Vec<Shard<Q>>>
+///
+/// Shards with a persisted session resume it instead of identifying, as long as the total number
+/// of shards has not changed since the session was persisted.
+pub async fn obtain<Q>(
+    queue: Q,
+    assignment: &ShardAssignment,
+    sessions: &HashMap<u32, PersistedSession>,
+) -> miette::Result<Vec<Shard<Q>>>
 where
     Q: Queue + Clone + Send + Sync + Sized,
 {
-    let config = ConfigBuilder::new(TOKEN.deref().clone(), Intents::all())
+    let intents = intents()?;
+    let presence = Presence::from_env()?;
+    log::debug!("requesting gateway intents {intents:?}");
+
+    let config = ConfigBuilder::new(TOKEN.deref().clone(), intents)
         .queue(queue.clone())
         .build();
+    let per_shard_config = |shard_id: ShardId, mut builder: ConfigBuilder<Q>| {
+        if let Some(persisted) = sessions
+            .get(&shard_id.number())
+            .filter(|persisted| persisted.shard_total == shard_id.total())
+        {
+            builder = builder
+                .resume_url(persisted.resume_url.clone())
+                .session(persisted.session.clone());
+        }
+
+        builder
+            .presence(presence.payload(shard_id.number()))
+            .queue(queue.clone())
+            .build()
+    };
+
+    Ok(match assignment {
+        ShardAssignment::All => create_recommended::<_, Q>(&CLIENT, config, per_shard_config)
+            .await
+            .into_diagnostic()?
+            .collect::<Vec<_>>(),
+        ShardAssignment::Range { range, total } => {
+            create_range(range.clone(), *total, config, per_shard_config).collect::<Vec<_>>()
+        }
+    })
+}
+
+/// The gateway intents requested regardless of the plugins of the registered commands.
+///
+/// The worker requests the members of every guild it receives, along with their presences.
+const BASE_INTENTS: Intents = Intents::GUILDS
+    .union(Intents::GUILD_MEMBERS)
+    .union(Intents::GUILD_PRESENCES);
+
+/// Read the gateway intents to request from the environment.
+///
+/// `GATEWAY_INTENTS` is a comma-separated list of intent names, such as `GUILDS,GUILD_MEMBERS`,
+/// or `all` to request every intent. If it is unset, the intents needed by the plugins of the
+/// registered commands are requested in addition to the base intents.
+fn intents() -> miette::Result<Intents> {
+    let Some(intents) = non_empty_var("GATEWAY_INTENTS") else {
+        return Ok(BASE_INTENTS | registry::intents());
+    };
+    if intents.trim() == "all" {
+        return Ok(Intents::all());
+    }
+
+    intents
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .try_fold(Intents::empty(), |intents, name| {
+            Intents::from_name(name)
+                .map(|intent| intents | intent)
+                .ok_or_else(|| miette!("unknown gateway intent: {name}"))
+        })
+}
+
+/// Read an environment variable, treating an empty value as unset.
+fn non_empty_var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cluster_range;
+
+    #[test]
+    fn cluster_ranges_cover_every_shard_once() {
+        for total in 1..=32 {
+            for count in 1..=total {
+                let mut next = 0;
+                for index in 0..count {
+                    let range = cluster_range(index, count, total);
+
+                    assert_eq!(range.start, next);
+                    assert!(!range.is_empty());
+                    next = range.end;
+                }
+
+                assert_eq!(next, total);
+            }
+        }
+    }
+
+    #[test]
+    fn cluster_ranges_spread_the_remainder_over_the_first_clusters() {
+        assert_eq!(cluster_range(0, 3, 4), 0..2);
+        assert_eq!(cluster_range(1, 3, 4), 2..3);
+        assert_eq!(cluster_range(2, 3, 4), 3..4);
+    }
 
-    Ok(create_recommended::<_, Q>(
-        &CLIENT,
-        config,
-        |shard_id: ShardId, builder: ConfigBuilder<Q>| {
-            builder
-                .presence(UpdatePresencePayload {
-                    activities: vec![Activity {
-                        application_id: None,
-                        assets: None,
-                        buttons: vec![],
-                        created_at: None,
-                        details: None,
-                        emoji: None,
-                        flags: None,
-                        id: None,
-                        instance: None,
-                        kind: ActivityType::Watching,
-                        name: format!("development | shard {}", shard_id.number()),
-                        party: None,
-                        secrets: None,
-                        state: None,
-                        timestamps: None,
-                        url: None,
-                    }],
-                    afk: false,
-                    since: None,
-                    status: Status::Online,
-                })
-                .queue(queue.clone())
-                .build()
-        },
-    )
-    .await
-    .into_diagnostic()?
-    .collect::<Vec<_>>())
+    #[test]
+    fn cluster_ranges_are_even_without_remainder() {
+        assert_eq!(cluster_range(0, 2, 8), 0..4);
+        assert_eq!(cluster_range(1, 2, 8), 4..8);
+    }
 }
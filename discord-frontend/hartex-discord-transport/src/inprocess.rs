@@ -0,0 +1,115 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # In-Process Transport
+//!
+//! Each channel is backed by an unbounded channel with a single subscriber, which is sufficient
+//! when every component runs within the same process.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use hartex_discord_core::tokio::sync::mpsc::UnboundedReceiver;
+use hartex_discord_core::tokio::sync::mpsc::UnboundedSender;
+use hartex_discord_core::tokio::sync::mpsc::unbounded_channel;
+use miette::miette;
+
+use crate::Channel;
+use crate::Record;
+
+/// The sending and receiving halves of an in-process channel, the latter until subscribed to.
+type ChannelHalves = (
+    UnboundedSender<Record>,
+    Mutex<Option<UnboundedReceiver<Record>>>,
+);
+
+/// A transport backed by in-process channels.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
+pub struct InProcessTransport {
+    channels: Arc<HashMap<Channel, ChannelHalves>>,
+}
+
+impl InProcessTransport {
+    /// Creates an in-process transport.
+    #[must_use]
+    pub fn new() -> Self {
+        let channels = Channel::ALL
+            .into_iter()
+            .map(|channel| {
+                let (tx, rx) = unbounded_channel();
+                (channel, (tx, Mutex::new(Some(rx))))
+            })
+            .collect();
+
+        Self {
+            channels: Arc::new(channels),
+        }
+    }
+
+    /// Publishes a record to a channel.
+    pub(crate) fn publish(
+        &self,
+        channel: Channel,
+        key: Option<&[u8]>,
+        payload: &[u8],
+    ) -> miette::Result<()> {
+        let (tx, _) = &self.channels[&channel];
+
+        tx.send(Record {
+            key: key.map(<[u8]>::to_vec),
+            payload: payload.to_vec(),
+        })
+        .map_err(|_| miette!("the {} channel has been closed", channel.name()))
+    }
+
+    /// Subscribes to a channel.
+    ///
+    /// Each channel can only be subscribed to once.
+    pub(crate) fn subscribe(&self, channel: Channel) -> miette::Result<InProcessSubscription> {
+        let (_, rx) = &self.channels[&channel];
+
+        rx.lock()
+            .map_err(|_| miette!("the {} channel has been poisoned", channel.name()))?
+            .take()
+            .map(InProcessSubscription)
+            .ok_or_else(|| miette!("the {} channel already has a subscriber", channel.name()))
+    }
+}
+
+impl Default for InProcessTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A subscription to an in-process channel.
+#[allow(clippy::module_name_repetitions)]
+pub struct InProcessSubscription(UnboundedReceiver<Record>);
+
+impl InProcessSubscription {
+    /// Receives the next record.
+    pub(crate) async fn next(&mut self) -> Option<Record> {
+        self.0.recv().await
+    }
+}
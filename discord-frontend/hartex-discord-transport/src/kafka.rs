@@ -0,0 +1,179 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Kafka Transport
+//!
+//! Each channel is backed by a Kafka topic, read from the environment.
+
+use std::collections::HashMap;
+use std::env;
+use std::time::Duration;
+
+use hartex_kafka_utils::deadletter::DeadLetter;
+use hartex_kafka_utils::traits::ClientConfigUtils;
+use hartex_kafka_utils::types::CompressionType;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use rdkafka::ClientConfig;
+use rdkafka::Message;
+use rdkafka::consumer::Consumer;
+use rdkafka::consumer::StreamConsumer;
+use rdkafka::producer::FutureProducer;
+use rdkafka::producer::FutureRecord;
+use rdkafka::util::Timeout;
+
+use crate::Channel;
+use crate::Record;
+
+/// A transport backed by Kafka topics.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone)]
+pub struct KafkaTransport {
+    bootstrap_servers: Vec<String>,
+    producer: FutureProducer,
+    topics: HashMap<Channel, String>,
+}
+
+impl KafkaTransport {
+    /// Creates a Kafka transport from the bootstrap servers and topics in the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the environment variables are missing, or if the producer
+    /// cannot be created.
+    pub fn from_env() -> miette::Result<Self> {
+        let bootstrap_servers = env::var("KAFKA_BOOTSTRAP_SERVERS")
+            .into_diagnostic()?
+            .split(';')
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let topics = Channel::ALL
+            .into_iter()
+            .map(|channel| {
+                env::var(channel.topic_variable())
+                    .into_diagnostic()
+                    .map(|topic| (channel, topic))
+            })
+            .collect::<miette::Result<HashMap<_, _>>>()?;
+
+        let producer = ClientConfig::new()
+            .bootstrap_servers(bootstrap_servers.clone().into_iter())
+            .compression_type(CompressionType::Lz4)
+            .delivery_timeout_ms(30000)
+            .create::<FutureProducer>()
+            .into_diagnostic()?;
+
+        Ok(Self {
+            bootstrap_servers,
+            producer,
+            topics,
+        })
+    }
+
+    /// Returns the topic backing a channel.
+    #[must_use]
+    pub fn topic(&self, channel: Channel) -> &str {
+        &self.topics[&channel]
+    }
+
+    /// Publishes a record to the topic backing a channel.
+    pub(crate) async fn publish(
+        &self,
+        channel: Channel,
+        key: Option<&[u8]>,
+        payload: &[u8],
+    ) -> miette::Result<()> {
+        let mut record = FutureRecord::<[u8], [u8]>::to(self.topic(channel)).payload(payload);
+        if let Some(key) = key {
+            record = record.key(key);
+        }
+
+        self.producer
+            .send(record, Timeout::After(Duration::from_secs(0)))
+            .await
+            .map_err(|(error, _)| error)
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+
+    /// Publishes a record to the dead-letter topic.
+    pub(crate) async fn publish_dead_letter(
+        &self,
+        dead_letter: &DeadLetter,
+        key: Option<&[u8]>,
+        payload: &[u8],
+    ) -> miette::Result<()> {
+        dead_letter
+            .publish(
+                &self.producer,
+                self.topic(Channel::InboundGatewayPayloadDeadLetter),
+                key,
+                payload,
+            )
+            .await
+            .into_diagnostic()
+    }
+
+    /// Subscribes to the topic backing a channel.
+    pub(crate) fn subscribe(
+        &self,
+        channel: Channel,
+        group_id: &str,
+    ) -> miette::Result<KafkaSubscription> {
+        let consumer = ClientConfig::new()
+            .bootstrap_servers(self.bootstrap_servers.clone().into_iter())
+            .group_id(group_id)
+            .create::<StreamConsumer>()
+            .into_diagnostic()?;
+        consumer
+            .subscribe(&[self.topic(channel)])
+            .into_diagnostic()?;
+
+        Ok(KafkaSubscription(consumer))
+    }
+}
+
+/// A subscription to a Kafka topic.
+#[allow(clippy::module_name_repetitions)]
+pub struct KafkaSubscription(StreamConsumer);
+
+impl KafkaSubscription {
+    /// Receives the next record with a payload.
+    pub(crate) async fn next(&mut self) -> Option<Record> {
+        loop {
+            match self.0.recv().await {
+                Ok(message) => {
+                    let Some(payload) = message.payload() else {
+                        continue;
+                    };
+
+                    return Some(Record {
+                        key: message.key().map(<[u8]>::to_vec),
+                        payload: payload.to_vec(),
+                    });
+                }
+                Err(error) => log::warn!("failed to receive record: {error}"),
+            }
+        }
+    }
+}
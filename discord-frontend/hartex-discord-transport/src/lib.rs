@@ -0,0 +1,243 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Discord Frontend Transport
+//!
+//! The leader, the worker and the entitycache service exchange records over a transport. Kafka is
+//! used in production, while the in-process transport allows running every component within a
+//! single process, without a Kafka broker, for local development and integration tests.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use std::sync::OnceLock;
+
+use hartex_discord_internal_events::InternalEvent;
+use hartex_kafka_utils::deadletter::DeadLetter;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use miette::miette;
+
+use crate::inprocess::InProcessSubscription;
+use crate::inprocess::InProcessTransport;
+use crate::kafka::KafkaSubscription;
+use crate::kafka::KafkaTransport;
+
+pub mod inprocess;
+pub mod kafka;
+
+/// The transport installed for this process.
+static INSTALLED: OnceLock<Transport> = OnceLock::new();
+
+/// A channel the components of the bot communicate through.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Channel {
+    /// Gateway payloads forwarded by the leader to the worker.
+    InboundGatewayPayload,
+    /// Gateway payloads forwarded by the leader to the entitycache service.
+    InboundGatewayPayloadCache,
+    /// Gateway payloads that could not be processed.
+    InboundGatewayPayloadDeadLetter,
+    /// Internal events exchanged between the services.
+    InternalEvent,
+    /// Gateway commands sent to the leader.
+    OutboundCommunication,
+}
+
+impl Channel {
+    /// Every channel.
+    pub const ALL: [Self; 5] = [
+        Self::InboundGatewayPayload,
+        Self::InboundGatewayPayloadCache,
+        Self::InboundGatewayPayloadDeadLetter,
+        Self::InternalEvent,
+        Self::OutboundCommunication,
+    ];
+
+    /// The name of the channel.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::InboundGatewayPayload => "inbound-gateway-payload",
+            Self::InboundGatewayPayloadCache => "inbound-gateway-payload-cache",
+            Self::InboundGatewayPayloadDeadLetter => "inbound-gateway-payload-dead-letter",
+            Self::InternalEvent => "internal-event",
+            Self::OutboundCommunication => "outbound-communication",
+        }
+    }
+
+    /// The environment variable containing the Kafka topic backing the channel.
+    #[must_use]
+    pub const fn topic_variable(self) -> &'static str {
+        match self {
+            Self::InboundGatewayPayload => "KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD",
+            Self::InboundGatewayPayloadCache => "KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_CACHE",
+            Self::InboundGatewayPayloadDeadLetter => {
+                "KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_DEAD_LETTER"
+            }
+            Self::InternalEvent => "KAFKA_TOPIC_INTERNAL_EVENT_PAYLOAD",
+            Self::OutboundCommunication => "KAFKA_TOPIC_OUTBOUND_COMMUNICATION",
+        }
+    }
+}
+
+/// A record exchanged over a transport.
+#[derive(Clone, Debug)]
+pub struct Record {
+    /// The key of the record.
+    pub key: Option<Vec<u8>>,
+    /// The payload of the record.
+    pub payload: Vec<u8>,
+}
+
+/// A transport records are exchanged over.
+#[derive(Clone)]
+pub enum Transport {
+    /// The in-process transport.
+    InProcess(InProcessTransport),
+    /// The Kafka transport.
+    Kafka(KafkaTransport),
+}
+
+impl Transport {
+    /// Returns the transport installed for this process, if any.
+    ///
+    /// This is used by components that are not handed a transport explicitly, such as commands.
+    #[must_use]
+    pub fn installed() -> Option<&'static Self> {
+        INSTALLED.get()
+    }
+
+    /// Installs the transport for this process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a transport has already been installed.
+    pub fn install(&self) -> miette::Result<()> {
+        INSTALLED
+            .set(self.clone())
+            .map_err(|_| miette!("a transport has already been installed"))
+    }
+
+    /// Returns the name of the origin of records received from a channel, recorded in dead
+    /// letters.
+    #[must_use]
+    pub fn origin(&self, channel: Channel) -> String {
+        match self {
+            Self::InProcess(_) => String::from(channel.name()),
+            Self::Kafka(kafka) => kafka.topic(channel).to_string(),
+        }
+    }
+
+    /// Publishes a record to a channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be published.
+    pub async fn publish(
+        &self,
+        channel: Channel,
+        key: Option<&[u8]>,
+        payload: &[u8],
+    ) -> miette::Result<()> {
+        match self {
+            Self::InProcess(in_process) => in_process.publish(channel, key, payload),
+            Self::Kafka(kafka) => kafka.publish(channel, key, payload).await,
+        }
+    }
+
+    /// Publishes an internal event to a channel, keyed by the guild or shard the event concerns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event cannot be encoded or published.
+    pub async fn publish_event(
+        &self,
+        channel: Channel,
+        event: &InternalEvent,
+    ) -> miette::Result<()> {
+        let payload = event.encode().into_diagnostic()?;
+
+        self.publish(channel, Some(event.key().as_bytes()), &payload)
+            .await
+    }
+
+    /// Publishes a record that could not be processed to the dead-letter channel.
+    ///
+    /// The in-process transport has nowhere to keep dead letters for later inspection, so they are
+    /// logged instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be published.
+    pub async fn publish_dead_letter(
+        &self,
+        dead_letter: &DeadLetter,
+        key: Option<&[u8]>,
+        payload: &[u8],
+    ) -> miette::Result<()> {
+        match self {
+            Self::InProcess(_) => {
+                log::error!(
+                    "dead letter from {consumer} ({origin}): {error}",
+                    consumer = dead_letter.consumer,
+                    origin = dead_letter.origin_topic,
+                    error = dead_letter.error
+                );
+
+                Ok(())
+            }
+            Self::Kafka(kafka) => kafka.publish_dead_letter(dead_letter, key, payload).await,
+        }
+    }
+
+    /// Subscribes to a channel as part of the given consumer group.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be created.
+    pub fn subscribe(&self, channel: Channel, group_id: &str) -> miette::Result<Subscription> {
+        Ok(match self {
+            Self::InProcess(in_process) => Subscription::InProcess(in_process.subscribe(channel)?),
+            Self::Kafka(kafka) => Subscription::Kafka(kafka.subscribe(channel, group_id)?),
+        })
+    }
+}
+
+/// A subscription to a channel.
+pub enum Subscription {
+    /// A subscription to an in-process channel.
+    InProcess(InProcessSubscription),
+    /// A subscription to a Kafka topic.
+    Kafka(KafkaSubscription),
+}
+
+impl Subscription {
+    /// Receives the next record, returning `None` once the channel is closed.
+    pub async fn next(&mut self) -> Option<Record> {
+        match self {
+            Self::InProcess(in_process) => in_process.next().await,
+            Self::Kafka(kafka) => kafka.next().await,
+        }
+    }
+}
@@ -25,26 +25,45 @@ use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
-/// Consumer error.
+/// Command registry error.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, Debug)]
-pub struct ConsumerError {
+pub struct RegistryError {
     /// The error type.
-    pub kind: ConsumerErrorKind,
+    pub kind: RegistryErrorKind,
 }
 
-impl Display for ConsumerError {
+impl Display for RegistryError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self.kind {
-            ConsumerErrorKind::InvalidGatewayPayload => f.write_str("invalid gateway payload"),
+        match &self.kind {
+            RegistryErrorKind::SpecificationMismatch {
+                unimplemented,
+                unspecified,
+            } => write!(
+                f,
+                "command registry does not match the command specifications; commands specified but not implemented: [{}]; commands implemented but not specified: [{}]",
+                unimplemented.join(", "),
+                unspecified.join(", ")
+            ),
+            RegistryErrorKind::DuplicateCommands { duplicates } => write!(
+                f,
+                "multiple commands are registered with the same name: [{}]",
+                duplicates.join(", ")
+            ),
         }
     }
 }
 
-impl Error for ConsumerError {}
+impl Error for RegistryError {}
 
-/// The type of consumer error that has occurred.
+/// The type of command registry error that has occurred.
 #[derive(Clone, Debug)]
-pub enum ConsumerErrorKind {
-    InvalidGatewayPayload,
+pub enum RegistryErrorKind {
+    DuplicateCommands {
+        duplicates: Vec<String>,
+    },
+    SpecificationMismatch {
+        unimplemented: Vec<String>,
+        unspecified: Vec<String>,
+    },
 }
@@ -27,8 +27,11 @@
 use std::env;
 use std::str::FromStr;
 
+use chrono::DateTime;
 use chrono::Utc;
-use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_database_queries::queries::discord_frontend::error_report_insert::ErrorReportInsert;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
 use hartex_discord_core::discord::model::gateway::payload::incoming::InteractionCreate;
 use hartex_discord_core::discord::model::id::Id;
 use hartex_discord_core::discord::model::id::marker::ChannelMarker;
@@ -44,15 +47,21 @@ use sha2::Sha224;
 
 /// This function handle errors from an interaction. It does the following things:
 ///
-/// (1) generate a unique error code;
-/// (2) send a message to a designated channel for error logs in the support server with the error code; and
-/// (3) responds to the interaction with the error message with the error code.
+/// (1) generate a unique error code, derived from the error, the time and the ID of the interaction,
+///     such that errors reported for different interactions in the same second do not collide;
+/// (2) persist the error along with the context of the interaction, such that it can be looked up
+///     by its error code;
+/// (3) send a message to a designated channel for error logs in the support server with the error code; and
+/// (4) responds to the interaction with the error message with the error code; if the interaction
+///     has already been deferred or responded to, the original response is edited or a follow-up
+///     message is sent instead.
 pub async fn handle_interaction_error(
     payload: ErrorPayload,
     interaction_create: Box<InteractionCreate>,
-    interaction_client: &InteractionClient<'_>,
+    context: &ResponseContext<'_>,
 ) {
     let mut hasher = Sha224::new();
+    let timestamp = Utc::now();
 
     let channel_id_str = env::var("ERROR_CHANNEL_ID").unwrap();
     let channel_id = Id::<ChannelMarker>::from_str(channel_id_str.as_str()).unwrap();
@@ -62,20 +71,28 @@ pub async fn handle_interaction_error(
             let report = strip_ansi_escapes::strip_str(report.to_string());
 
             hasher.update(report.as_bytes());
-            hasher.update(Utc::now().timestamp().to_string().as_bytes());
+            hasher.update(timestamp.timestamp().to_string().as_bytes());
+            hasher.update(interaction_create.id.to_string().as_bytes());
 
             let output = hasher.finalize();
             let hash = output.map(|int| format!("{int:x}")).join("");
-            interaction_client
-                .create_response(
-                    interaction_create.id,
-                    &interaction_create.token,
-                    &ephemeral_error_response(format!(
-                        ":x: This command encountered an unexpected error. Please provide the following error code for support.\n\nError code: {}", hash.clone().discord_inline_code()
-                    ))
-                )
+
+            log::warn!("command errorred: {report:?}; error hash: {hash}");
+
+            if let Err(error) =
+                persist_error_report(&hash, "error", &report, &interaction_create, timestamp).await
+            {
+                log::error!("failed to persist error report {hash}: {error:?}");
+            }
+
+            if let Err(error) = context
+                .respond(&ephemeral_error_response(format!(
+                    ":x: This command encountered an unexpected error. Please provide the following error code for support.\n\nError code: {}", hash.clone().discord_inline_code()
+                )))
                 .await
-                .unwrap();
+            {
+                log::error!("failed to respond with error code {hash}: {error:?}");
+            }
 
             let embed = EmbedBuilder::new()
                 .color(0xFF_99_33)
@@ -88,37 +105,45 @@ pub async fn handle_interaction_error(
                     "Error",
                     report.clone().discord_codeblock(),
                 ))
-                .validate()
-                .unwrap()
-                .build();
-
-            CLIENT
-                .create_message(channel_id)
-                .embeds(&[embed])
-                .await
-                .unwrap();
-
-            log::warn!("command errorred: {report:?}; error hash: {hash}");
+                .validate();
+            let embed = match embed {
+                Ok(embed) => embed.build(),
+                Err(error) => {
+                    log::error!("failed to build error report embed for {hash}: {error:?}");
+                    return;
+                }
+            };
+
+            if let Err(error) = CLIENT.create_message(channel_id).embeds(&[embed]).await {
+                log::error!("failed to send error report {hash} to the error channel: {error:?}");
+            }
         }
         ErrorPayload::Panic(message) => {
             let message = strip_ansi_escapes::strip_str(message);
 
             hasher.update(message.as_bytes());
-            hasher.update(Utc::now().timestamp().to_string().as_bytes());
+            hasher.update(timestamp.timestamp().to_string().as_bytes());
+            hasher.update(interaction_create.id.to_string().as_bytes());
 
             let output = hasher.finalize();
             let hash = output.map(|int| format!("{int:x}")).join("");
 
-            interaction_client
-                .create_response(
-                    interaction_create.id,
-                    &interaction_create.token,
-                    &ephemeral_error_response(format!(
-                        ":x: This command encountered an critical error. Please provide the following error code for support.\n\nError code: {}", hash.clone().discord_inline_code()
-                    )),
-                )
+            log::error!("interaction command panicked: {message:?}; error hash: {hash}");
+
+            if let Err(error) =
+                persist_error_report(&hash, "panic", &message, &interaction_create, timestamp).await
+            {
+                log::error!("failed to persist error report {hash}: {error:?}");
+            }
+
+            if let Err(error) = context
+                .respond(&ephemeral_error_response(format!(
+                    ":x: This command encountered an critical error. Please provide the following error code for support.\n\nError code: {}", hash.clone().discord_inline_code()
+                )))
                 .await
-                .unwrap();
+            {
+                log::error!("failed to respond with error code {hash}: {error:?}");
+            }
 
             let embed = EmbedBuilder::new()
                 .color(0xFF_33_33)
@@ -131,21 +156,60 @@ pub async fn handle_interaction_error(
                     "Error",
                     message.clone().discord_codeblock(),
                 ))
-                .validate()
-                .unwrap()
-                .build();
-
-            CLIENT
-                .create_message(channel_id)
-                .embeds(&[embed])
-                .await
-                .unwrap();
-
-            log::error!("interaction command panicked: {message:?}; error hash: {hash}");
+                .validate();
+            let embed = match embed {
+                Ok(embed) => embed.build(),
+                Err(error) => {
+                    log::error!("failed to build error report embed for {hash}: {error:?}");
+                    return;
+                }
+            };
+
+            if let Err(error) = CLIENT.create_message(channel_id).embeds(&[embed]).await {
+                log::error!("failed to send error report {hash} to the error channel: {error:?}");
+            }
         }
     }
 }
 
+/// Persists an error report along with the context of the interaction that caused it.
+async fn persist_error_report(
+    hash: &str,
+    kind: &str,
+    report: &str,
+    interaction_create: &InteractionCreate,
+    timestamp: DateTime<Utc>,
+) -> hartex_database_queries::result::Result<()> {
+    let interaction = &interaction_create.0;
+    let command = match &interaction.data {
+        Some(InteractionData::ApplicationCommand(data)) => Some(data.name.clone()),
+        Some(InteractionData::MessageComponent(data)) => Some(data.custom_id.clone()),
+        Some(InteractionData::ModalSubmit(data)) => Some(data.custom_id.clone()),
+        _ => None,
+    };
+    let options = interaction
+        .data
+        .as_ref()
+        .and_then(|data| serde_json::to_string(data).ok());
+
+    ErrorReportInsert::bind(
+        hash.to_string(),
+        kind.to_string(),
+        report.to_string(),
+        command,
+        interaction.guild_id.map(|id| id.to_string()),
+        interaction.author_id().map(|id| id.to_string()),
+        options,
+        timestamp,
+    )
+    .executor()
+    .await?
+    .execute()
+    .await?;
+
+    Ok(())
+}
+
 /// The error payload received.
 pub enum ErrorPayload {
     /// A `miette` report payload.
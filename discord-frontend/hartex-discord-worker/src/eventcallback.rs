@@ -22,11 +22,11 @@
 
 use std::env;
 use std::panic::AssertUnwindSafe;
-use std::time::Duration;
 use std::time::SystemTime;
 
 use futures_util::FutureExt;
 use hartex_backend_models::uptime::UptimeUpdate;
+use hartex_discord_commands_core::response::ResponseContext;
 use hartex_discord_core::discord::model::application::interaction::InteractionType;
 use hartex_discord_core::discord::model::gateway::OpCode;
 use hartex_discord_core::discord::model::gateway::event::DispatchEvent;
@@ -36,6 +36,12 @@ use hartex_discord_core::discord::model::gateway::payload::outgoing::RequestGuil
 use hartex_discord_core::discord::model::gateway::payload::outgoing::request_guild_members::RequestGuildMembersInfo;
 use hartex_discord_core::tokio::net::TcpStream;
 use hartex_discord_core::tokio::spawn;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_discord_internal_events::outbound::OutboundGatewayCommand;
+use hartex_discord_internal_events::outbound::OutboundGatewayCommandEnvelope;
+use hartex_discord_internal_events::outbound::OutboundTarget;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
 use hartex_discord_utils::CLIENT;
 use hartex_log::log;
 use hyper::Method;
@@ -45,10 +51,6 @@ use hyper::header::ACCEPT;
 use hyper::header::CONTENT_TYPE;
 use hyper_util::rt::TokioIo;
 use miette::IntoDiagnostic;
-use rdkafka::error::KafkaError;
-use rdkafka::producer::FutureProducer;
-use rdkafka::producer::FutureRecord;
-use rdkafka::util::Timeout;
 
 use crate::errorhandler::ErrorPayload;
 
@@ -56,13 +58,7 @@ use crate::errorhandler::ErrorPayload;
 #[allow(clippy::cast_lossless)]
 #[allow(clippy::large_futures)]
 #[allow(clippy::too_many_lines)]
-pub async fn invoke(
-    event: GatewayEvent,
-    shard: u8,
-    producer: FutureProducer,
-) -> miette::Result<()> {
-    let topic = env::var("KAFKA_TOPIC_OUTBOUND_COMMUNICATION").into_diagnostic()?;
-
+pub async fn invoke(event: GatewayEvent, shard: u32, transport: Transport) -> miette::Result<()> {
     #[allow(clippy::collapsible_match)]
     match event {
         GatewayEvent::Dispatch(seq, dispatch) => match dispatch {
@@ -82,17 +78,21 @@ pub async fn invoke(
                     },
                     op: OpCode::RequestGuildMembers,
                 };
-                let string = serde_json::to_string(&request).into_diagnostic()?;
-                if let Err((error, _)) = producer
-                    .send(
-                        FutureRecord::to(&topic)
-                            .key(&format!("OUTBOUND_REQUEST_GUILD_MEMBERS_{shard}"))
-                            .payload(&string),
-                        Timeout::After(Duration::from_secs(0)),
+                let envelope = OutboundGatewayCommandEnvelope::new(
+                    OutboundGatewayCommand::RequestGuildMembers(request),
+                    OutboundTarget::Guild(guild_create.id),
+                );
+                if let Err(error) = transport
+                    .publish_event(
+                        Channel::OutboundCommunication,
+                        &InternalEvent::OutboundGatewayCommand(envelope),
                     )
                     .await
                 {
-                    println!("{:?}", Err::<(), KafkaError>(error).into_diagnostic());
+                    log::error!(
+                        "[shard {shard}] failed to request members of guild {}: {error:?}",
+                        guild_create.id
+                    );
                 }
 
                 Ok(())
@@ -105,10 +105,101 @@ pub async fn invoke(
                 );
 
                 let interaction_client = CLIENT.interaction(interaction_create.application_id);
+                let context = ResponseContext::new(&interaction_client, &interaction_create);
 
                 if let Err(error) = AssertUnwindSafe(crate::interaction::application_command(
                     interaction_create.clone(),
-                    &interaction_client,
+                    &context,
+                ))
+                .catch_unwind()
+                .await
+                {
+                    crate::errorhandler::handle_interaction_error(
+                        ErrorPayload::Panic(
+                            error
+                                .downcast_ref::<String>()
+                                .unwrap_or(&String::new())
+                                .to_string(),
+                        ),
+                        interaction_create,
+                        &context,
+                    )
+                    .await;
+                }
+
+                Ok(())
+            }
+            DispatchEvent::InteractionCreate(interaction_create)
+                if interaction_create.kind == InteractionType::ApplicationCommandAutocomplete =>
+            {
+                log::trace!(
+                    "shard {shard} has received INTERACTION_CREATE payload from Discord (sequence {seq})"
+                );
+
+                let interaction_client = CLIENT.interaction(interaction_create.application_id);
+                let context = ResponseContext::new(&interaction_client, &interaction_create);
+
+                match AssertUnwindSafe(crate::interaction::application_command_autocomplete(
+                    interaction_create,
+                    &context,
+                ))
+                .catch_unwind()
+                .await
+                {
+                    Ok(result) => result?,
+                    Err(error) => log::error!(
+                        "interaction command autocomplete panicked: {:?}",
+                        error.downcast_ref::<String>().unwrap_or(&String::new())
+                    ),
+                }
+
+                Ok(())
+            }
+            DispatchEvent::InteractionCreate(interaction_create)
+                if interaction_create.kind == InteractionType::MessageComponent =>
+            {
+                log::trace!(
+                    "shard {shard} has received INTERACTION_CREATE payload from Discord (sequence {seq})"
+                );
+
+                let interaction_client = CLIENT.interaction(interaction_create.application_id);
+                let context = ResponseContext::new(&interaction_client, &interaction_create);
+
+                if let Err(error) = AssertUnwindSafe(crate::interaction::message_component(
+                    interaction_create.clone(),
+                    &context,
+                ))
+                .catch_unwind()
+                .await
+                {
+                    crate::errorhandler::handle_interaction_error(
+                        ErrorPayload::Panic(
+                            error
+                                .downcast_ref::<String>()
+                                .unwrap_or(&String::new())
+                                .to_string(),
+                        ),
+                        interaction_create,
+                        &context,
+                    )
+                    .await;
+                }
+
+                Ok(())
+            }
+            DispatchEvent::InteractionCreate(interaction_create)
+                if interaction_create.kind == InteractionType::ModalSubmit =>
+            {
+                log::trace!(
+                    "shard {shard} has received INTERACTION_CREATE payload from Discord (sequence {seq})"
+                );
+
+                let interaction_client = CLIENT.interaction(interaction_create.application_id);
+                let context = ResponseContext::new(&interaction_client, &interaction_create);
+
+                if let Err(error) = AssertUnwindSafe(crate::interaction::modal_submit(
+                    interaction_create.clone(),
+                    &context,
                 ))
                 .catch_unwind()
                 .await
@@ -121,7 +212,7 @@ pub async fn invoke(
                                 .to_string(),
                         ),
                         interaction_create,
-                        &interaction_client,
+                        &context,
                     )
                     .await;
                 }
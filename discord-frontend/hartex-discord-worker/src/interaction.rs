@@ -21,117 +21,442 @@
  */
 
 use std::collections::HashMap;
+use std::pin::pin;
 use std::sync::LazyLock;
+use std::time::Duration;
 
-use hartex_discord_commands::general::about::About;
-use hartex_discord_commands::general::contributors::Contributors;
-use hartex_discord_commands::utilities::info::Info;
+use hartex_discord_commands_core::components::CustomId;
+use hartex_discord_commands_core::cooldowns::Cooldown;
+use hartex_discord_commands_core::cooldowns::CooldownBucket;
+use hartex_discord_commands_core::cooldowns::CooldownTracker;
+use hartex_discord_commands_core::permissions::CommandPermissions;
+use hartex_discord_commands_core::permissions::PermissionSubject;
+use hartex_discord_commands_core::registry;
+use hartex_discord_commands_core::response::ResponseContext;
+use hartex_discord_commands_core::targets::MessageTarget;
+use hartex_discord_commands_core::targets::UserTarget;
 use hartex_discord_commands_core::traits::Command;
 use hartex_discord_commands_core::traits::CommandMetadata;
-use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::model::application::command::CommandType;
 use hartex_discord_core::discord::model::application::interaction::InteractionData;
 use hartex_discord_core::discord::model::gateway::payload::incoming::InteractionCreate;
 use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
 use hartex_discord_core::discord::model::http::interaction::InteractionResponseType;
 use hartex_discord_core::discord::util::builder::InteractionResponseDataBuilder;
+use hartex_discord_core::tokio;
+use hartex_discord_core::tokio::time;
+use hartex_discord_utils::interaction::autocomplete_response;
+use hartex_discord_utils::interaction::ephemeral_error_response;
 use hartex_localization_core::LOCALIZATION_HOLDER;
 use hartex_localization_core::Localizer;
 use hartex_log::log;
-use miette::IntoDiagnostic;
 
 use crate::errorhandler::ErrorPayload;
 
+/// The time after which the response to an application command is automatically deferred if the
+/// command has not started responding yet, leaving some headroom before the three second deadline
+/// imposed by Discord.
+const AUTOMATIC_DEFER_THRESHOLD: Duration = Duration::from_millis(2000);
+
 /// Lookup table for commands provided by the bot.
 ///
 /// This is used for retrieving the command instance by its name such that precommand checks
-/// can be executed via dynamic dispatch without the need of match arms and if guards.
+/// can be executed via dynamic dispatch without the need of match arms and if guards. The table
+/// is filled from the command registry, which commands are added to by the `#[command]` macro;
+/// commands sharing a name are rejected on startup before the table is used.
 pub static COMMAND_LOOKUP: LazyLock<HashMap<String, Box<dyn Command + Send + Sync>>> =
     LazyLock::new(|| {
-        let mut map = HashMap::<String, Box<dyn Command + Send + Sync>>::new();
-        map.insert(About.name(), Box::new(About));
-        map.insert(Contributors.name(), Box::new(Contributors));
-        map.insert(Info.name(), Box::new(Info));
-        map
+        registry::commands()
+            .map(|command| (command.name(), command))
+            .collect()
     });
 
+/// Tracks the cooldowns of the commands provided by the bot.
+static COOLDOWN_TRACKER: LazyLock<CooldownTracker> = LazyLock::new(CooldownTracker::new);
+
+/// Handle an application command autocomplete interaction.
+///
+/// Errors are only logged here, as autocomplete interactions cannot be responded to with a
+/// message containing an error code.
+pub async fn application_command_autocomplete(
+    interaction_create: Box<InteractionCreate>,
+    context: &ResponseContext<'_>,
+) -> miette::Result<()> {
+    let InteractionData::ApplicationCommand(command) = interaction_create.data.clone().unwrap()
+    else {
+        unreachable!("this should not be possible")
+    };
+
+    log::trace!(
+        "running autocomplete for interaction command {}",
+        &command.name
+    );
+
+    let locale = interaction_create.locale.as_deref().unwrap_or("en-GB");
+    let localizer = Localizer::new(&LOCALIZATION_HOLDER, locale);
+
+    let Some(command) = COMMAND_LOOKUP.get(&command.name) else {
+        log::warn!(
+            "no command found for autocomplete of command {}",
+            &command.name
+        );
+        context.respond(&autocomplete_response(Vec::new())).await?;
+
+        return Ok(());
+    };
+    if let Some(guild_id) = interaction_create.guild_id
+        && !command.plugin().enabled(guild_id).await?
+    {
+        context.respond(&autocomplete_response(Vec::new())).await?;
+
+        return Ok(());
+    }
+
+    if let Err(error) = command
+        .autocomplete(interaction_create.0, context, localizer)
+        .await
+    {
+        log::warn!("command autocomplete errorred: {error:?}");
+    }
+
+    Ok(())
+}
+
 /// Handle an application command interaction.
+///
+/// The command is dispatched according to its type, such that context menu commands receive the
+/// user or message they are invoked on. If the command has not started responding shortly before
+/// the deadline imposed by Discord, the response is deferred. The timer only starts once the
+/// precommand checks have passed, such that commands responding with a modal, which cannot follow
+/// a deferred response, are not deferred because of slow checks.
 #[allow(clippy::large_futures)]
 pub async fn application_command(
     interaction_create: Box<InteractionCreate>,
-    interaction_client: &InteractionClient<'_>,
+    context: &ResponseContext<'_>,
 ) -> miette::Result<()> {
-    let InteractionData::ApplicationCommand(command) = interaction_create.data.clone().unwrap()
-    else {
+    let InteractionData::ApplicationCommand(data) = interaction_create.data.clone().unwrap() else {
         unreachable!("this should not be possible")
     };
 
-    log::trace!("running interaction command {}", &command.name);
+    log::trace!("running interaction command {}", &data.name);
 
     let cloned = interaction_create.clone();
 
     let locale = interaction_create.locale.as_deref().unwrap_or("en-GB");
     let localizer = Localizer::new(&LOCALIZATION_HOLDER, locale);
 
-    let command = COMMAND_LOOKUP.get(&command.name).unwrap();
-    let plugin = command.plugin();
-    if !plugin.enabled(interaction_create.guild_id.unwrap()).await? {
-        interaction_client
-            .create_response(
-                interaction_create.id,
-                &interaction_create.token,
-                &InteractionResponse {
-                    kind: InteractionResponseType::ChannelMessageWithSource,
-                    data: Some(
-                        InteractionResponseDataBuilder::new()
-                            .content(localizer.error_error_plugin_disabled(plugin.name())?)
-                            .build(),
-                    ),
-                },
-            )
-            .await
-            .into_diagnostic()?;
+    let Some(command) = COMMAND_LOOKUP.get(&data.name) else {
+        log::warn!("no command found for interaction command {}", &data.name);
+        context
+            .respond(&ephemeral_error_response(
+                localizer.error_error_unknown_command()?,
+            ))
+            .await?;
 
         return Ok(());
+    };
+
+    let result = match precommand_checks(command.as_ref(), &cloned, context, &localizer).await {
+        Ok(false) => return Ok(()),
+        Ok(true) => {
+            let mut execution = pin!(async {
+                match data.kind {
+                    CommandType::User => {
+                        let target = UserTarget::from_command_data(&data).unwrap();
+                        command
+                            .execute_user(cloned.0, target, context, localizer)
+                            .await
+                    }
+                    CommandType::Message => {
+                        let target = MessageTarget::from_command_data(&data).unwrap();
+                        command
+                            .execute_message(cloned.0, target, context, localizer)
+                            .await
+                    }
+                    _ => command.execute(cloned.0, context, localizer).await,
+                }
+            });
+
+            tokio::select! {
+                result = &mut execution => result,
+                () = time::sleep(AUTOMATIC_DEFER_THRESHOLD) => {
+                    log::trace!("interaction command is taking long; deferring the response");
+                    if let Err(error) = context.defer(false).await {
+                        log::warn!("failed to defer the response automatically: {error:?}");
+                    }
+
+                    execution.await
+                }
+            }
+        }
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        crate::errorhandler::handle_interaction_error(
+            ErrorPayload::Miette(error),
+            interaction_create,
+            context,
+        )
+        .await;
+    }
+
+    Ok(())
+}
+
+/// Run the checks preceding the execution of a command, responding to the interaction if any of
+/// them fails.
+///
+/// Returns whether the command is to be executed.
+async fn precommand_checks(
+    command: &(dyn Command + Send + Sync),
+    interaction_create: &InteractionCreate,
+    context: &ResponseContext<'_>,
+    localizer: &Localizer<'_>,
+) -> miette::Result<bool> {
+    // guild configurations and permission overrides only apply to commands invoked in guilds
+    let guild_id = interaction_create.guild_id;
+
+    let plugin = command.plugin();
+    if let Some(guild_id) = guild_id
+        && !plugin.enabled(guild_id).await?
+    {
+        context
+            .respond(&InteractionResponse {
+                kind: InteractionResponseType::ChannelMessageWithSource,
+                data: Some(
+                    InteractionResponseDataBuilder::new()
+                        .content(localizer.error_error_plugin_disabled(plugin.name())?)
+                        .build(),
+                ),
+            })
+            .await?;
+
+        return Ok(false);
+    }
+
+    if let Some(guild_id) = guild_id
+        && let Some(subject) = PermissionSubject::from_interaction(&interaction_create.0)
+        && !CommandPermissions::load(guild_id, &command.name())
+            .await?
+            .evaluate(&subject, command.required_permissions())
+            .allowed()
+    {
+        context
+            .respond(&InteractionResponse {
+                kind: InteractionResponseType::ChannelMessageWithSource,
+                data: Some(
+                    InteractionResponseDataBuilder::new()
+                        .content(localizer.error_error_insufficient_permissions()?)
+                        .build(),
+                ),
+            })
+            .await?;
+
+        return Ok(false);
     }
 
-    let permissions = command.required_permissions();
-    let member_permissions = interaction_create
-        .member
-        .clone()
-        .unwrap()
-        .permissions
-        .unwrap();
-    if !member_permissions.contains(permissions) {
-        interaction_client
-            .create_response(
-                interaction_create.id,
-                &interaction_create.token,
-                &InteractionResponse {
-                    kind: InteractionResponseType::ChannelMessageWithSource,
-                    data: Some(
-                        InteractionResponseDataBuilder::new()
-                            .content(localizer.error_error_insufficient_permissions()?)
-                            .build(),
-                    ),
-                },
-            )
-            .await
-            .into_diagnostic()?;
+    let configured = match guild_id {
+        Some(guild_id) => ConfigurationProvider::command_cooldown(guild_id, command.name()).await?,
+        None => None,
+    };
+    let cooldown = match configured {
+        Some(configured) => match CooldownBucket::from_name(configured.bucket()) {
+            Some(bucket) => Some(Cooldown::new(
+                bucket,
+                Duration::from_secs(u64::try_from(configured.seconds()).unwrap_or_default()),
+            )),
+            None => {
+                log::warn!(
+                    "unknown cooldown bucket {} configured for command {} in guild {}; falling back to its default cooldown",
+                    configured.bucket(),
+                    command.name(),
+                    configured.guild_id()
+                );
+
+                command.cooldown()
+            }
+        },
+        None => command.cooldown(),
+    };
+
+    if let Some(cooldown) = cooldown
+        && !cooldown.duration.is_zero()
+        && let Some(key) = cooldown.bucket.key(&interaction_create.0)
+        && let Err(remaining) = COOLDOWN_TRACKER.trigger(&command.name(), cooldown, key)
+    {
+        let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        context
+            .respond(&ephemeral_error_response(
+                localizer.error_error_command_on_cooldown(seconds)?,
+            ))
+            .await?;
 
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Handle a message component interaction.
+///
+/// The component is routed to the command its custom ID is namespaced under.
+#[allow(clippy::large_futures)]
+pub async fn message_component(
+    interaction_create: Box<InteractionCreate>,
+    context: &ResponseContext<'_>,
+) -> miette::Result<()> {
+    let InteractionData::MessageComponent(component) = interaction_create.data.clone().unwrap()
+    else {
+        unreachable!("this should not be possible")
+    };
+
+    log::trace!("running message component {}", &component.custom_id);
+
+    let locale = interaction_create.locale.as_deref().unwrap_or("en-GB");
+    let localizer = Localizer::new(&LOCALIZATION_HOLDER, locale);
+
+    let custom_id = match component.custom_id.parse::<CustomId>() {
+        Ok(custom_id) => custom_id,
+        Err(error) => {
+            log::warn!("malformed custom id {}: {error}", &component.custom_id);
+            context
+                .respond(&ephemeral_error_response(
+                    localizer.error_error_invalid_component()?,
+                ))
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let Some(command) =
+        namespaced_command(&custom_id, &interaction_create, context, &localizer).await?
+    else {
         return Ok(());
+    };
+
+    if let Err(error) = command
+        .component(interaction_create.0.clone(), context, custom_id, localizer)
+        .await
+    {
+        crate::errorhandler::handle_interaction_error(
+            ErrorPayload::Miette(error),
+            interaction_create,
+            context,
+        )
+        .await;
     }
 
+    Ok(())
+}
+
+/// Handle a modal submit interaction.
+///
+/// The modal is routed to the command its custom ID is namespaced under.
+#[allow(clippy::large_futures)]
+pub async fn modal_submit(
+    interaction_create: Box<InteractionCreate>,
+    context: &ResponseContext<'_>,
+) -> miette::Result<()> {
+    let InteractionData::ModalSubmit(modal) = interaction_create.data.clone().unwrap() else {
+        unreachable!("this should not be possible")
+    };
+
+    log::trace!("running modal submit {}", &modal.custom_id);
+
+    let locale = interaction_create.locale.as_deref().unwrap_or("en-GB");
+    let localizer = Localizer::new(&LOCALIZATION_HOLDER, locale);
+
+    let custom_id = match modal.custom_id.parse::<CustomId>() {
+        Ok(custom_id) => custom_id,
+        Err(error) => {
+            log::warn!("malformed custom id {}: {error}", &modal.custom_id);
+            context
+                .respond(&ephemeral_error_response(
+                    localizer.error_error_invalid_component()?,
+                ))
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let Some(command) =
+        namespaced_command(&custom_id, &interaction_create, context, &localizer).await?
+    else {
+        return Ok(());
+    };
+
     if let Err(error) = command
-        .execute(cloned.0, interaction_client, localizer)
+        .modal_submit(interaction_create.0.clone(), context, custom_id, localizer)
         .await
     {
         crate::errorhandler::handle_interaction_error(
             ErrorPayload::Miette(error),
             interaction_create,
-            interaction_client,
+            context,
         )
         .await;
     }
 
     Ok(())
 }
+
+/// Looks up the command a custom ID is namespaced under.
+///
+/// Returns `None` if no such command exists, if the command does not belong to the plugin
+/// specified in the custom ID, or if the plugin is disabled, in which case the interaction is
+/// responded to accordingly.
+async fn namespaced_command(
+    custom_id: &CustomId,
+    interaction_create: &InteractionCreate,
+    context: &ResponseContext<'_>,
+    localizer: &Localizer<'_>,
+) -> miette::Result<Option<&'static (dyn Command + Send + Sync)>> {
+    let Some(command) = COMMAND_LOOKUP.get(&custom_id.command) else {
+        log::warn!("no command found for custom id {custom_id}");
+        context
+            .respond(&ephemeral_error_response(
+                localizer.error_error_invalid_component()?,
+            ))
+            .await?;
+
+        return Ok(None);
+    };
+
+    let plugin = command.plugin();
+    if plugin.name() != custom_id.plugin {
+        log::warn!(
+            "custom id {custom_id} does not belong to plugin {}",
+            plugin.name()
+        );
+        context
+            .respond(&ephemeral_error_response(
+                localizer.error_error_invalid_component()?,
+            ))
+            .await?;
+
+        return Ok(None);
+    }
+
+    if let Some(guild_id) = interaction_create.guild_id
+        && !plugin.enabled(guild_id).await?
+    {
+        context
+            .respond(&InteractionResponse {
+                kind: InteractionResponseType::ChannelMessageWithSource,
+                data: Some(
+                    InteractionResponseDataBuilder::new()
+                        .content(localizer.error_error_plugin_disabled(plugin.name())?)
+                        .build(),
+                ),
+            })
+            .await?;
+
+        return Ok(None);
+    }
+
+    Ok(Some(command.as_ref()))
+}
@@ -0,0 +1,112 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Cache Invalidation
+//!
+//! The worker keeps entities in the in-memory tier of the entitycache, which the entitycache update
+//! service invalidates through internal events once it has updated the cache. The statistics of
+//! the in-memory tier are logged every minute while it is enabled.
+
+use std::env;
+use std::time::Duration;
+
+use hartex_discord_core::tokio::task::spawn;
+use hartex_discord_core::tokio::time;
+use hartex_discord_entitycache_repositories::memory;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
+use hartex_log::log;
+use miette::miette;
+
+/// The prefix of the consumer group of the worker consuming internal events.
+///
+/// Every worker joins a consumer group of its own, named after its instance, as each of them has to
+/// receive every cache invalidation.
+const INTERNAL_EVENT_CONSUMER_GROUP_ID_PREFIX: &str =
+    "com.github.teamhartex.hartex.internal.event.worker.consumer";
+
+/// The interval at which the statistics of the in-memory tier are logged.
+const STATS_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Start applying cache invalidations to the in-memory tier of the entitycache in the background.
+///
+/// Nothing is listened for if the in-memory tier is disabled, as there is nothing to invalidate.
+pub fn listen(transport: &Transport) -> miette::Result<()> {
+    if !memory::enabled() {
+        log::trace!("in-memory tier is disabled, not listening for cache invalidations");
+
+        return Ok(());
+    }
+
+    let instance_id = env::var("WORKER_INSTANCE_ID")
+        .ok()
+        .filter(|id| !id.is_empty())
+        .or_else(|| env::var("HOSTNAME").ok())
+        .ok_or_else(|| miette!("neither WORKER_INSTANCE_ID nor HOSTNAME is set"))?;
+    let group_id = format!("{INTERNAL_EVENT_CONSUMER_GROUP_ID_PREFIX}.{instance_id}");
+    let mut subscription = transport.subscribe(Channel::InternalEvent, &group_id)?;
+
+    spawn(async move {
+        while let Some(record) = subscription.next().await {
+            match InternalEvent::decode(&record.payload) {
+                Ok(InternalEvent::CacheInvalidation(invalidation)) => {
+                    log::trace!("invalidating cached entities: {invalidation:?}");
+                    memory::invalidate(&invalidation);
+                }
+                Ok(_) => (),
+                Err(error) => log::warn!("failed to decode internal event: {error}"),
+            }
+        }
+    });
+
+    spawn(async {
+        let mut interval = time::interval(STATS_INTERVAL);
+        loop {
+            interval.tick().await;
+            log_stats();
+        }
+    });
+
+    Ok(())
+}
+
+/// Log the statistics of the in-memory tier of each entity type.
+fn log_stats() {
+    let tiers = [
+        ("channel", memory::CHANNELS.stats()),
+        ("emoji", memory::EMOJIS.stats()),
+        ("guild", memory::GUILDS.stats()),
+        ("member", memory::MEMBERS.stats()),
+        ("role", memory::ROLES.stats()),
+        ("user", memory::USERS.stats()),
+    ];
+
+    for (kind, stats) in tiers {
+        log::info!(
+            "in-memory {kind} tier: {} entities, {} hits, {} misses",
+            stats.entries,
+            stats.hits,
+            stats.misses
+        );
+    }
+}
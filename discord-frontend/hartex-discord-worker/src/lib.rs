@@ -0,0 +1,131 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Worker
+//!
+//! The worker receives gateway payloads from the leader and handles them, most notably invoking
+//! commands. It runs either as its own process, or together with the other components in a single
+//! process.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+#![allow(incomplete_features)]
+#![feature(deref_patterns)]
+#![feature(let_chains)]
+
+// the commands crate is not otherwise referenced, but needs to be linked for its commands to be
+// added to the command registry
+extern crate hartex_discord_commands;
+
+use hartex_discord_internal_events::inbound::InboundGatewayPayloadKey;
+use hartex_discord_transport::Channel;
+use hartex_discord_transport::Transport;
+use hartex_discord_utils::dispatch::EventDispatcher;
+use hartex_discord_utils::inbound::InboundPayload;
+use hartex_kafka_utils::deadletter::DeadLetter;
+use hartex_log::log;
+
+mod error;
+mod errorhandler;
+mod eventcallback;
+mod interaction;
+mod invalidation;
+mod specification;
+
+/// The consumer group of the worker consuming inbound gateway payloads.
+const INBOUND_CONSUMER_GROUP_ID: &str =
+    "com.github.teamhartex.hartex.inbound.gateway.payload.consumer";
+
+/// Run the worker until the inbound gateway payload channel is closed.
+///
+/// # Errors
+///
+/// Returns an error if the worker cannot be started.
+#[allow(clippy::large_futures)]
+pub async fn run(transport: Transport) -> miette::Result<()> {
+    log::trace!("verifying command registry");
+    specification::verify_command_registry()?;
+
+    log::trace!("listening for cache invalidations");
+    invalidation::listen(&transport)?;
+
+    let channel = Channel::InboundGatewayPayload;
+    let mut subscription = transport.subscribe(channel, INBOUND_CONSUMER_GROUP_ID)?;
+
+    let dispatcher = EventDispatcher::from_env("WORKER_MAX_IN_FLIGHT_EVENTS")?;
+
+    while let Some(record) = subscription.next().await {
+        let inbound = match InboundPayload::decode(
+            InboundGatewayPayloadKey::shard_of(record.key.as_deref()),
+            &record.payload,
+        ) {
+            Ok(inbound) => inbound,
+            Err(error) => {
+                log::warn!(
+                    "failed to decode gateway payload, sending to dead-letter topic: {error}"
+                );
+
+                let dead_letter = DeadLetter {
+                    consumer: String::from("worker"),
+                    error: error.to_string(),
+                    event_type: error.event_type,
+                    origin_topic: transport.origin(channel),
+                    shard: error.shard,
+                };
+                if let Err(error) = transport
+                    .publish_dead_letter(&dead_letter, record.key.as_deref(), &record.payload)
+                    .await
+                {
+                    log::error!(
+                        "failed to publish gateway payload with key {} to dead-letter topic: {error:?}",
+                        String::from_utf8_lossy(record.key.as_deref().unwrap_or_default())
+                    );
+                }
+
+                continue;
+            }
+        };
+
+        let InboundPayload {
+            event,
+            event_type,
+            guild_id,
+            shard,
+        } = inbound;
+        log::trace!(
+            "[shard {shard}] received {} event",
+            event_type.as_deref().unwrap_or("UNKNOWN")
+        );
+
+        let transport = transport.clone();
+        dispatcher
+            .dispatch(guild_id, async move {
+                if let Err(error) = eventcallback::invoke(event, shard, transport).await {
+                    log::error!("[shard {shard}] failed to handle event: {error:?}");
+                }
+            })
+            .await;
+    }
+
+    Ok(())
+}
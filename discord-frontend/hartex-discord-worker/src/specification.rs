@@ -0,0 +1,139 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Specifications
+//!
+//! The commands in the command registry are cross-checked by name and type against the command
+//! specifications on startup, such that commands that are registered with Discord but not
+//! implemented, or vice versa, are caught early. Commands registered more than once under the same
+//! name are caught as well, as only one of them would be reachable.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::Read;
+
+use hartex_discord_commands_core::registry;
+use hartex_discord_commands_core::traits::CommandMetadata;
+use hartex_discord_core::discord::model::application::command::CommandType;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use serde::Deserialize;
+use walkdir::WalkDir;
+
+use crate::error::RegistryError;
+use crate::error::RegistryErrorKind;
+use crate::interaction::COMMAND_LOOKUP;
+
+/// The directory containing the command specifications.
+const SPECIFICATION_DIRECTORY: &str = "hartex-discord-commands-spec";
+
+/// The part of a command specification relevant for cross-checking.
+#[derive(Deserialize)]
+struct CommandSpecification {
+    name: String,
+    #[serde(rename = "type")]
+    kind: CommandType,
+}
+
+/// Verifies that no two commands are registered with the same name, that every specified command
+/// is implemented, and that every implemented command is specified.
+pub fn verify_command_registry() -> miette::Result<()> {
+    let mut registrations = BTreeMap::<String, usize>::new();
+    for command in registry::commands() {
+        *registrations.entry(command.name()).or_default() += 1;
+    }
+
+    let duplicates = registrations
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    if !duplicates.is_empty() {
+        return Err(RegistryError {
+            kind: RegistryErrorKind::DuplicateCommands { duplicates },
+        })
+        .into_diagnostic();
+    }
+
+    log::trace!("reading specification directory");
+    log::warn!(
+        "an error will occur if the worker is not ran within the discord-frontend directory"
+    );
+
+    let mut specified = BTreeSet::new();
+    for result in WalkDir::new(SPECIFICATION_DIRECTORY).same_file_system(true) {
+        let entry = result.into_diagnostic()?;
+        if entry.metadata().into_diagnostic()?.is_dir() {
+            continue;
+        }
+
+        if entry
+            .path()
+            .extension()
+            .and_then(|extension| extension.to_str())
+            != Some("json")
+        {
+            continue;
+        }
+
+        let mut buffer = String::new();
+        File::open(entry.path())
+            .into_diagnostic()?
+            .read_to_string(&mut buffer)
+            .into_diagnostic()?;
+
+        let specification =
+            serde_json::from_str::<CommandSpecification>(&buffer).into_diagnostic()?;
+        specified.insert(format!("{} ({:?})", specification.name, specification.kind));
+    }
+
+    let implemented = COMMAND_LOOKUP
+        .values()
+        .map(|command| format!("{} ({:?})", command.name(), command.kind()))
+        .collect::<BTreeSet<_>>();
+
+    let unimplemented = specified
+        .difference(&implemented)
+        .cloned()
+        .collect::<Vec<_>>();
+    let unspecified = implemented
+        .difference(&specified)
+        .cloned()
+        .collect::<Vec<_>>();
+    if !unimplemented.is_empty() || !unspecified.is_empty() {
+        return Err(RegistryError {
+            kind: RegistryErrorKind::SpecificationMismatch {
+                unimplemented,
+                unspecified,
+            },
+        })
+        .into_diagnostic();
+    }
+
+    log::trace!(
+        "{} commands verified against specifications",
+        implemented.len()
+    );
+
+    Ok(())
+}
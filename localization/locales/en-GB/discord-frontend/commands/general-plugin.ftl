@@ -30,3 +30,9 @@ contributors-embed-global-admin-field-name=Global Administrator & Lead Developer
 contributors-embed-front-dev-field-name=Frontend Developer
 contributors-embed-translation-team-field-name=Translation Team
 contributors-embed-footer=This is not a complete list (preserve more screen real estate) - but thanks to all who contributed!
+latency-embed-average-field-name=Average
+latency-embed-shard-field-name=Shard {$shardId}
+latency-embed-title=Latency
+latency-error-unavailable=Shard latency is currently unavailable. Please try again later.
+uptime-embed-started-field-name=Started
+uptime-embed-title=Uptime
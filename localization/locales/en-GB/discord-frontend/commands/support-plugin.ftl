@@ -0,0 +1,34 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+errorlookup-embed-title=Error Report `{$code}`
+errorlookup-embed-kind-field-name=Kind
+errorlookup-embed-command-field-name=Command
+errorlookup-embed-timestamp-field-name=Occurred
+errorlookup-embed-guild-field-name=Guild
+errorlookup-embed-user-field-name=User
+errorlookup-embed-options-field-name=Interaction Options
+errorlookup-embed-unknown=Unknown
+errorlookup-error-not-found=No error report was found for the error code `{$code}`.
+presence-updated=The presence has been sent to all shards.
+support-error-not-in-support-guild=This command can only be used in the support server.
+support-error-not-owner=This command can only be used by the owners of the bot.
@@ -32,6 +32,25 @@ emojiinfo-embed-generalinfo-name-subfield-name=Name:
 emojiinfo-error-only-custom-emojis=Sorry! Only custom emojis are supported at this stage.
 emojiinfo-error-only-one-emoji=Querying multiple emojis is not supported.
 emojiinfo-error-unknown-emoji=Unknown emoji. Perhaps the bot is not in a server that has this emoji?
+permissionscheck-embed-description=Permission check of {$command} for {$user} in {$channel}
+permissionscheck-embed-result-field-name=Result
+permissionscheck-error-not-a-member=The specified user is not a member of this server.
+permissionscheck-error-unknown-command=Unknown command `{$command}`.
+permissionscheck-reason-allowed-by-permissions=The permissions required by the command are held.
+permissionscheck-reason-allowed-by-rule=Allowed by the rule for {$target}.
+permissionscheck-reason-denied-by-permissions=Missing permissions required by the command: {$permissions}
+permissionscheck-reason-denied-by-rule=Denied by the rule for {$target}.
+permissionscheck-result-allowed=:white_check_mark: Allowed
+permissionscheck-result-denied=:x: Denied
+reportmessage-embed-author-field-name=Author
+reportmessage-embed-message-field-name=Message
+reportmessage-embed-reason-field-name=Reason
+reportmessage-embed-reporter-field-name=Reported By
+reportmessage-embed-title=Message Reported
+reportmessage-error-not-configured=Reporting messages is not set up in this server.
+reportmessage-modal-reason-label=Reason
+reportmessage-modal-title=Report Message
+reportmessage-success=Thank you! The message has been reported to the moderators.
 roleinfo-embed-description=Information of {$roleMention}
 roleinfo-embed-generalinfo-field-name=General Information
 roleinfo-embed-generalinfo-id-subfield-name=ID:
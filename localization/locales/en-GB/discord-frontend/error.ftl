@@ -27,4 +27,8 @@ error-line-one=:x: This command encountered{ $errorKind ->
 } error. Please provide the following error code for support.
 error-line-two=Error code:
 error-plugin-disabled=The `{$plugin}` plugin is not enabled. Please enable it in the guild configuration.
-error-insufficient-permissions=Invoking user has insufficient permissions.
\ No newline at end of file
+error-command-on-cooldown=This command is on cooldown. Please try again in {$seconds} seconds.
+error-insufficient-permissions=Invoking user has insufficient permissions.
+error-invalid-component=This component is not valid. Please invoke the command again.
+error-unknown-command=This command is not available. Please try again later.
+error-guild-only=This command can only be used in a server.
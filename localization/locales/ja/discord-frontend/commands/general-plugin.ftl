@@ -30,3 +30,9 @@ contributors-embed-global-admin-field-name=グローバル管理人＆統率開
 contributors-embed-front-dev-field-name=フロントエンド開発
 contributors-embed-translation-team-field-name=翻訳チーム
 contributors-embed-footer=不完全だけど（何卒スクリーンの値段高い不動産を保つやで）、全部の貢献者にありがとう！
+latency-embed-average-field-name=平均
+latency-embed-shard-field-name=シャード {$shardId}
+latency-embed-title=遅延
+latency-error-unavailable=シャードの遅延情報は現在利用できません。後でもう一度お試しください。
+uptime-embed-started-field-name=起動時刻
+uptime-embed-title=平常運転時間
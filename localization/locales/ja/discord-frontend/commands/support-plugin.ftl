@@ -0,0 +1,34 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+errorlookup-embed-title=エラー報告 `{$code}`
+errorlookup-embed-kind-field-name=種類
+errorlookup-embed-command-field-name=コマンド
+errorlookup-embed-timestamp-field-name=発生時刻
+errorlookup-embed-guild-field-name=サーバー
+errorlookup-embed-user-field-name=ユーザー
+errorlookup-embed-options-field-name=インタラクションのオプション
+errorlookup-embed-unknown=不明
+errorlookup-error-not-found=エラーコード `{$code}` のエラー報告が見つからなかった。
+presence-updated=プレゼンスを全シャードに送信した。
+support-error-not-in-support-guild=このコマンドはサポートサーバーでのみ使用できる。
+support-error-not-owner=このコマンドはボットのオーナーのみ使用できる。
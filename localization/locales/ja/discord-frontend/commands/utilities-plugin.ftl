@@ -32,6 +32,25 @@ emojiinfo-embed-generalinfo-name-subfield-name=名：
 emojiinfo-error-only-custom-emojis=申し訳ありませんが、今時点はカスタム絵文字しかサポートしてません。
 emojiinfo-error-only-one-emoji=複数の絵文字を問いかけることができません。
 emojiinfo-error-unknown-emoji=不明な絵文字。この絵文字がある鯖を参加してないかもしれません。
+permissionscheck-embed-description={$channel} での {$user} の {$command} の権限確認
+permissionscheck-embed-result-field-name=結果
+permissionscheck-error-not-a-member=指定されたユーザーはこのサーバーのメンバーではありません。
+permissionscheck-error-unknown-command=`{$command}` というコマンドはありません。
+permissionscheck-reason-allowed-by-permissions=コマンドに必要な権限を持っています。
+permissionscheck-reason-allowed-by-rule={$target} のルールで許可されています。
+permissionscheck-reason-denied-by-permissions=コマンドに必要な権限がありません：{$permissions}
+permissionscheck-reason-denied-by-rule={$target} のルールで拒否されています。
+permissionscheck-result-allowed=:white_check_mark: 許可
+permissionscheck-result-denied=:x: 拒否
+reportmessage-embed-author-field-name=送信者
+reportmessage-embed-message-field-name=メッセージ
+reportmessage-embed-reason-field-name=理由
+reportmessage-embed-reporter-field-name=報告者
+reportmessage-embed-title=メッセージが報告されました
+reportmessage-error-not-configured=このサーバーではメッセージの報告が設定されていません。
+reportmessage-modal-reason-label=理由
+reportmessage-modal-title=メッセージを報告
+reportmessage-success=ありがとうございます！メッセージをモデレーターに報告しました。
 roleinfo-embed-description={$roleMention}の情報
 roleinfo-embed-generalinfo-field-name=一般情報
 roleinfo-embed-generalinfo-id-subfield-name=ID：
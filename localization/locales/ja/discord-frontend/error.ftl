@@ -31,4 +31,8 @@ error-line-one=
 
 error-line-two=エラーコード：
 error-plugin-disabled=`{$plugin}` が無効です。本鯖の設定にこのプラグインを有効にしてください。
+error-command-on-cooldown=このコマンドはクールダウン中です。{$seconds}秒後にもう一度お試しください。
 error-insufficient-permissions=ユーザーは必要な許可がありません。
+error-invalid-component=このコンポーネントは無効です。もう一度コマンドを実行してください。
+error-unknown-command=このコマンドは利用できません。しばらくしてからもう一度お試しください。
+error-guild-only=このコマンドはサーバー内でのみ使用できます。
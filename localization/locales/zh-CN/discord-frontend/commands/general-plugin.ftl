@@ -30,3 +30,9 @@ contributors-embed-global-admin-field-name=全局管理员 & 首席开发人员
 contributors-embed-front-dev-field-name=前端开发人员
 contributors-embed-translation-team-field-name=翻译组
 contributors-embed-footer=这并不是一个完整的名单（保留更多的屏幕空间）但感谢所有的贡献者！
+latency-embed-average-field-name=平均
+latency-embed-shard-field-name=分片 {$shardId}
+latency-embed-title=延迟
+latency-error-unavailable=目前无法获取分片延迟。请稍后再试。
+uptime-embed-started-field-name=启动时间
+uptime-embed-title=正常运行时间
@@ -0,0 +1,34 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+errorlookup-embed-title=错误报告 `{$code}`
+errorlookup-embed-kind-field-name=类型
+errorlookup-embed-command-field-name=命令
+errorlookup-embed-timestamp-field-name=发生时间
+errorlookup-embed-guild-field-name=服务器
+errorlookup-embed-user-field-name=用户
+errorlookup-embed-options-field-name=交互选项
+errorlookup-embed-unknown=未知
+errorlookup-error-not-found=找不到错误代码 `{$code}` 的错误报告。
+presence-updated=状态已发送至所有分片。
+support-error-not-in-support-guild=此命令只能在支持服务器中使用。
+support-error-not-owner=此命令只能由机器人的所有者使用。
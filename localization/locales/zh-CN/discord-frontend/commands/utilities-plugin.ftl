@@ -32,6 +32,25 @@ emojiinfo-embed-generalinfo-name-subfield-name=名称：
 emojiinfo-error-only-custom-emojis=抱歉！现阶段仅支持自定义表情符号。
 emojiinfo-error-only-one-emoji=不支持查询多个表情符号。
 emojiinfo-error-unknown-emoji=未知表情符号，也许机器人没有在所选表情符号的服务器中？
+permissionscheck-embed-description={$user}在{$channel}中使用{$command}的权限检查
+permissionscheck-embed-result-field-name=结果
+permissionscheck-error-not-a-member=指定的用户不是此服务器的成员。
+permissionscheck-error-unknown-command=未知命令`{$command}`。
+permissionscheck-reason-allowed-by-permissions=拥有命令所需的权限。
+permissionscheck-reason-allowed-by-rule=已被{$target}的规则允许。
+permissionscheck-reason-denied-by-permissions=缺少命令所需的权限：{$permissions}
+permissionscheck-reason-denied-by-rule=已被{$target}的规则拒绝。
+permissionscheck-result-allowed=:white_check_mark: 允许
+permissionscheck-result-denied=:x: 拒绝
+reportmessage-embed-author-field-name=发送者
+reportmessage-embed-message-field-name=消息
+reportmessage-embed-reason-field-name=原因
+reportmessage-embed-reporter-field-name=举报者
+reportmessage-embed-title=消息已被举报
+reportmessage-error-not-configured=此服务器尚未设置消息举报。
+reportmessage-modal-reason-label=原因
+reportmessage-modal-title=举报消息
+reportmessage-success=谢谢！消息已举报给管理员。
 roleinfo-embed-description={$roleMention} 的信息
 roleinfo-embed-generalinfo-field-name=常规信息
 roleinfo-embed-generalinfo-id-subfield-name=ID：
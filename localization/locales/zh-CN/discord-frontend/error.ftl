@@ -27,4 +27,8 @@ error-line-one=:x: 次命令遇到了{ $errorKind ->
 }错误，请提供以下错误代码以获得支持。
 error-line-two=错误代码：
 error-plugin-disabled=`{$plugin}`插件未启用。请在服务器配置中启用。
-error-insufficient-permissions=使用命令的用户权限不足。
\ No newline at end of file
+error-command-on-cooldown=此命令正在冷却中。请在{$seconds}秒后重试。
+error-insufficient-permissions=使用命令的用户权限不足。
+error-invalid-component=此组件无效。请重新执行命令。
+error-unknown-command=此命令目前无法使用。请稍后再试。
+error-guild-only=此命令只能在服务器中使用。
@@ -30,3 +30,9 @@ contributors-embed-global-admin-field-name=全域管理員 & 首席開發人員
 contributors-embed-front-dev-field-name=前端開發人員
 contributors-embed-translation-team-field-name=翻譯團隊
 contributors-embed-footer=這不是完整的名單（以保留更多螢幕空間）－但感謝所有做出貢獻的人！
+latency-embed-average-field-name=平均
+latency-embed-shard-field-name=分片 {$shardId}
+latency-embed-title=延遲
+latency-error-unavailable=目前無法獲取分片延遲。請稍後再試。
+uptime-embed-started-field-name=啟動時間
+uptime-embed-title=正常運行時間
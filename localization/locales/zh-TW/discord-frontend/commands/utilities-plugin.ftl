@@ -32,6 +32,25 @@ emojiinfo-embed-generalinfo-name-subfield-name=名稱：
 emojiinfo-error-only-custom-emojis=抱歉！現階段僅支援自訂表情符號。
 emojiinfo-error-only-one-emoji=暫不支援多表情符號查詢。
 emojiinfo-error-unknown-emoji=未知表情符號。機器人可能不在擁有此表情符號的伺服器中？
+permissionscheck-embed-description={$user}在{$channel}中使用{$command}的權限檢查
+permissionscheck-embed-result-field-name=結果
+permissionscheck-error-not-a-member=指定的使用者不是此伺服器的成員。
+permissionscheck-error-unknown-command=未知指令`{$command}`。
+permissionscheck-reason-allowed-by-permissions=擁有指令所需的權限。
+permissionscheck-reason-allowed-by-rule=已被{$target}的規則允許。
+permissionscheck-reason-denied-by-permissions=缺少指令所需的權限：{$permissions}
+permissionscheck-reason-denied-by-rule=已被{$target}的規則拒絕。
+permissionscheck-result-allowed=:white_check_mark: 允許
+permissionscheck-result-denied=:x: 拒絕
+reportmessage-embed-author-field-name=傳送者
+reportmessage-embed-message-field-name=訊息
+reportmessage-embed-reason-field-name=原因
+reportmessage-embed-reporter-field-name=檢舉者
+reportmessage-embed-title=訊息已被檢舉
+reportmessage-error-not-configured=此伺服器尚未設定訊息檢舉。
+reportmessage-modal-reason-label=原因
+reportmessage-modal-title=檢舉訊息
+reportmessage-success=謝謝！訊息已檢舉給管理員。
 roleinfo-embed-description={$roleMention} 的資訊
 roleinfo-embed-generalinfo-field-name=一般資訊
 roleinfo-embed-generalinfo-id-subfield-name=ID：
@@ -27,4 +27,8 @@ error-line-one=:x: 這個指令發生了{ $errorKind ->
 }錯誤。請提供以下錯誤代碼以取得支援。
 error-line-two=錯誤代碼：
 error-plugin-disabled=插件 `{$plugin}` 未啟用。請在伺服器設定中啟用。
+error-command-on-cooldown=此指令正在冷卻中。請在{$seconds}秒後再試一次。
 error-insufficient-permissions=使用者權限不足。
+error-invalid-component=此元件無效。請重新執行指令。
+error-unknown-command=此指令目前無法使用。請稍後再試。
+error-guild-only=此指令只能在伺服器中使用。
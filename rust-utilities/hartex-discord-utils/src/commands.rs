@@ -50,6 +50,10 @@ pub trait CommandDataOptionsExt {
     /// Returns the value of a boolean option from a collection of options.
     fn boolean_value_of(&self, name: &str) -> bool;
 
+    /// Returns the name and the partially typed value of the option currently focused in an
+    /// autocomplete interaction, if any.
+    fn focused_option(&self) -> Option<(String, String)>;
+
     /// Returns the value of a role option from a collection of options.
     fn role_value_of(&self, name: &str) -> Id<RoleMarker>;
 
@@ -75,6 +79,13 @@ impl CommandDataOptionsExt for Vec<CommandDataOption> {
         boolean
     }
 
+    fn focused_option(&self) -> Option<(String, String)> {
+        self.iter().find_map(|option| match &option.value {
+            CommandOptionValue::Focused(value, _) => Some((option.name.clone(), value.clone())),
+            _ => None,
+        })
+    }
+
     fn role_value_of(&self, name: &str) -> Id<RoleMarker> {
         let CommandOptionValue::Role(role_id) = self
             .iter()
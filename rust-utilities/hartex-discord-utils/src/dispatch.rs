@@ -0,0 +1,182 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Event Dispatching
+//!
+//! Bounded concurrent processing of gateway events, preserving the order of events within the same
+//! guild.
+
+use std::collections::HashMap;
+use std::env;
+use std::num::NonZeroUsize;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use hartex_discord_core::discord::model::gateway::event::Event;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::tokio::spawn;
+use hartex_discord_core::tokio::sync::Semaphore;
+use hartex_discord_core::tokio::sync::mpsc::UnboundedReceiver;
+use hartex_discord_core::tokio::sync::mpsc::UnboundedSender;
+use hartex_discord_core::tokio::sync::mpsc::error::SendError;
+use hartex_discord_core::tokio::sync::mpsc::unbounded_channel;
+use miette::IntoDiagnostic;
+
+/// The maximum number of handlers running at once if not configured otherwise.
+pub const DEFAULT_MAX_IN_FLIGHT: NonZeroUsize = NonZeroUsize::new(64).unwrap();
+
+/// The number of handlers that may be dispatched but not yet running for every handler that may
+/// be running at once.
+const QUEUED_PER_IN_FLIGHT: usize = 4;
+
+/// An event handler that has been dispatched.
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// The lanes of the guilds that currently have handlers queued or running.
+type Lanes = Arc<Mutex<HashMap<Id<GuildMarker>, UnboundedSender<Job>>>>;
+
+/// Dispatches event handlers onto the runtime, with a bound on how many may be in flight at once.
+///
+/// Handlers dispatched for the same guild are run one after another in the order they were
+/// dispatched. Handlers that do not belong to a guild are run as soon as they are dispatched.
+///
+/// A handler only counts towards the maximum number of running handlers once it starts, such that
+/// handlers queued behind others of the same guild do not hold back those of other guilds.
+#[derive(Clone)]
+pub struct EventDispatcher {
+    lanes: Lanes,
+    queued: Arc<Semaphore>,
+    running: Arc<Semaphore>,
+}
+
+impl EventDispatcher {
+    /// Constructs a new dispatcher allowing at most `max_in_flight` handlers to be running at any
+    /// given time.
+    #[must_use]
+    pub fn new(max_in_flight: NonZeroUsize) -> Self {
+        Self {
+            lanes: Arc::new(Mutex::new(HashMap::new())),
+            queued: Arc::new(Semaphore::new(
+                max_in_flight.get().saturating_mul(QUEUED_PER_IN_FLIGHT),
+            )),
+            running: Arc::new(Semaphore::new(max_in_flight.get())),
+        }
+    }
+
+    /// Constructs a new dispatcher with the maximum number of running handlers read from the given
+    /// environment variable, falling back to [`DEFAULT_MAX_IN_FLIGHT`] if it is unset or empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment variable is not a positive integer.
+    pub fn from_env(key: &str) -> miette::Result<Self> {
+        let max_in_flight = match env::var(key) {
+            Ok(value) if !value.is_empty() => value.parse().into_diagnostic()?,
+            _ => DEFAULT_MAX_IN_FLIGHT,
+        };
+
+        Ok(Self::new(max_in_flight))
+    }
+
+    /// Dispatches a handler, waiting until there is room for it if the maximum number of queued
+    /// handlers has been reached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lane mutex has been poisoned.
+    pub async fn dispatch<F>(&self, guild_id: Option<Id<GuildMarker>>, handler: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let queued = self
+            .queued
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("the semaphore is never closed");
+        let running = self.running.clone();
+        let job: Job = Box::pin(async move {
+            let permit = running
+                .acquire_owned()
+                .await
+                .expect("the semaphore is never closed");
+            handler.await;
+            drop(permit);
+            drop(queued);
+        });
+
+        let Some(guild_id) = guild_id else {
+            spawn(job);
+            return;
+        };
+
+        let mut lanes = self.lanes.lock().unwrap();
+
+        // the lane may be gone if a previous handler in it has panicked
+        let job = match lanes.get(&guild_id) {
+            Some(sender) => match sender.send(job) {
+                Ok(()) => return,
+                Err(SendError(job)) => job,
+            },
+            None => job,
+        };
+
+        let (sender, receiver) = unbounded_channel();
+        sender.send(job).expect("the receiver is alive");
+        lanes.insert(guild_id, sender);
+
+        spawn(drain(guild_id, receiver, self.lanes.clone()));
+    }
+}
+
+/// Runs the handlers of a lane in order, removing the lane once it is empty.
+async fn drain(guild_id: Id<GuildMarker>, mut receiver: UnboundedReceiver<Job>, lanes: Lanes) {
+    loop {
+        // the lane is only removed while holding the lock, such that no handler can be sent to
+        // the lane after it has been found empty
+        let job = {
+            let mut lanes = lanes.lock().unwrap();
+            let Ok(job) = receiver.try_recv() else {
+                lanes.remove(&guild_id);
+                return;
+            };
+
+            job
+        };
+
+        job.await;
+    }
+}
+
+/// Determines the guild whose lane the handler of a gateway event is dispatched in.
+///
+/// Interactions are never ordered behind other events of their guild, as they have to be responded
+/// to within seconds of being received.
+#[must_use]
+pub fn lane_of_event(event: &Event) -> Option<Id<GuildMarker>> {
+    match event {
+        Event::InteractionCreate(_) => None,
+        _ => event.guild_id(),
+    }
+}
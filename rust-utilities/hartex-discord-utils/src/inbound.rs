@@ -0,0 +1,154 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Inbound Gateway Payloads
+//!
+//! Decoding of the gateway payloads forwarded by the leader.
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str;
+use std::str::Utf8Error;
+
+use hartex_discord_core::discord::model::gateway::event::DispatchEvent;
+use hartex_discord_core::discord::model::gateway::event::Event;
+use hartex_discord_core::discord::model::gateway::event::GatewayEvent;
+use hartex_discord_core::discord::model::gateway::event::GatewayEventDeserializer;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use serde::de::DeserializeSeed;
+
+use crate::dispatch::lane_of_event;
+
+/// A decoded gateway payload.
+pub struct InboundPayload {
+    /// The gateway event.
+    pub event: GatewayEvent,
+    /// The event type of the payload, if it is a dispatch.
+    pub event_type: Option<String>,
+    /// The guild whose lane the event is dispatched in, if any.
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// The shard the event was received on.
+    pub shard: u32,
+}
+
+impl InboundPayload {
+    /// Decodes a gateway payload received on the given shard, as read from the key of the record
+    /// it was forwarded in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the shard is missing or the payload is malformed.
+    pub fn decode(shard: Option<u32>, payload: &[u8]) -> Result<Self, InboundPayloadError> {
+        let error = |kind, event_type: Option<&str>| InboundPayloadError {
+            kind,
+            event_type: event_type.map(String::from),
+            shard,
+        };
+
+        let string = str::from_utf8(payload)
+            .map_err(|utf8_error| error(InboundPayloadErrorKind::InvalidUtf8(utf8_error), None))?;
+        let gateway_deserializer = GatewayEventDeserializer::from_json(string)
+            .ok_or_else(|| error(InboundPayloadErrorKind::InvalidGatewayPayload, None))?;
+        let event_type = gateway_deserializer.event_type().map(String::from);
+
+        let Some(shard) = shard else {
+            return Err(error(
+                InboundPayloadErrorKind::InvalidKey,
+                event_type.as_deref(),
+            ));
+        };
+
+        let mut json_deserializer = serde_json::Deserializer::from_slice(payload);
+        let event = gateway_deserializer
+            .deserialize(&mut json_deserializer)
+            .map_err(|json_error| {
+                error(
+                    InboundPayloadErrorKind::Deserialization(json_error),
+                    event_type.as_deref(),
+                )
+            })?;
+
+        let (event, guild_id) = match event {
+            GatewayEvent::Dispatch(sequence, dispatch) => {
+                let event = Event::from(dispatch);
+                let guild_id = lane_of_event(&event);
+                let Ok(dispatch) = DispatchEvent::try_from(event) else {
+                    unreachable!("the event has been converted from a dispatch event")
+                };
+
+                (GatewayEvent::Dispatch(sequence, dispatch), guild_id)
+            }
+            event => (event, None),
+        };
+
+        Ok(Self {
+            event,
+            event_type,
+            guild_id,
+            shard,
+        })
+    }
+}
+
+/// Inbound gateway payload error.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct InboundPayloadError {
+    /// The error type.
+    pub kind: InboundPayloadErrorKind,
+    /// The event type of the payload, if it could be determined.
+    pub event_type: Option<String>,
+    /// The shard the payload was received on, if it could be determined.
+    pub shard: Option<u32>,
+}
+
+impl Display for InboundPayloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            InboundPayloadErrorKind::Deserialization(error) => {
+                write!(f, "failed to deserialize gateway payload: {error}")
+            }
+            InboundPayloadErrorKind::InvalidGatewayPayload => {
+                f.write_str("invalid gateway payload")
+            }
+            InboundPayloadErrorKind::InvalidKey => f.write_str("invalid record key"),
+            InboundPayloadErrorKind::InvalidUtf8(error) => {
+                write!(f, "gateway payload is not valid UTF-8: {error}")
+            }
+        }
+    }
+}
+
+impl Error for InboundPayloadError {}
+
+/// The type of inbound gateway payload error that has occurred.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub enum InboundPayloadErrorKind {
+    Deserialization(serde_json::Error),
+    InvalidGatewayPayload,
+    InvalidKey,
+    InvalidUtf8(Utf8Error),
+}
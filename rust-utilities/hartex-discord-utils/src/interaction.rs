@@ -22,12 +22,29 @@
 
 //! # Utilities for Interaction Handling
 
+use hartex_discord_core::discord::model::application::command::CommandOptionChoice;
+use hartex_discord_core::discord::model::channel::message::Component;
 use hartex_discord_core::discord::model::channel::message::Embed;
 use hartex_discord_core::discord::model::channel::message::MessageFlags;
+use hartex_discord_core::discord::model::channel::message::component::ActionRow;
+use hartex_discord_core::discord::model::channel::message::component::TextInput;
 use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
 use hartex_discord_core::discord::model::http::interaction::InteractionResponseType;
 use hartex_discord_core::discord::util::builder::InteractionResponseDataBuilder;
 
+/// Constructs an autocomplete result response with the given choices.
+#[must_use]
+pub fn autocomplete_response(choices: Vec<CommandOptionChoice>) -> InteractionResponse {
+    InteractionResponse {
+        kind: InteractionResponseType::ApplicationCommandAutocompleteResult,
+        data: Some(
+            InteractionResponseDataBuilder::new()
+                .choices(choices)
+                .build(),
+        ),
+    }
+}
+
 /// Constructs an embed response.
 #[must_use]
 pub fn embed_response(embeds: Vec<Embed>) -> InteractionResponse {
@@ -37,6 +54,34 @@ pub fn embed_response(embeds: Vec<Embed>) -> InteractionResponse {
     }
 }
 
+/// Constructs an ephemeral embed response.
+#[must_use]
+pub fn ephemeral_embed_response(embeds: Vec<Embed>) -> InteractionResponse {
+    InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(
+            InteractionResponseDataBuilder::new()
+                .embeds(embeds)
+                .flags(MessageFlags::EPHEMERAL)
+                .build(),
+        ),
+    }
+}
+
+/// Constructs an ephemeral text response.
+#[must_use]
+pub fn ephemeral_response(message: impl Into<String>) -> InteractionResponse {
+    InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(
+            InteractionResponseDataBuilder::new()
+                .content(message)
+                .flags(MessageFlags::EPHEMERAL)
+                .build(),
+        ),
+    }
+}
+
 /// Constructs an ephemeral text response, used for error display.
 #[must_use]
 pub fn ephemeral_error_response(message: impl Into<String>) -> InteractionResponse {
@@ -50,3 +95,26 @@ pub fn ephemeral_error_response(message: impl Into<String>) -> InteractionRespon
         ),
     }
 }
+
+/// Constructs a modal response containing the given text inputs, each in its own action row.
+#[must_use]
+pub fn modal_response(
+    custom_id: impl Into<String>,
+    title: impl Into<String>,
+    text_inputs: Vec<TextInput>,
+) -> InteractionResponse {
+    InteractionResponse {
+        kind: InteractionResponseType::Modal,
+        data: Some(
+            InteractionResponseDataBuilder::new()
+                .custom_id(custom_id)
+                .title(title)
+                .components(text_inputs.into_iter().map(|text_input| {
+                    Component::ActionRow(ActionRow {
+                        components: vec![Component::TextInput(text_input)],
+                    })
+                }))
+                .build(),
+        ),
+    }
+}
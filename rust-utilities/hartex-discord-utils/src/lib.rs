@@ -41,11 +41,15 @@ use hartex_discord_core::discord::http::Client;
 use tokio_postgres::NoTls;
 
 pub mod commands;
+pub mod dispatch;
 pub mod hyper;
+pub mod inbound;
 pub mod interaction;
 pub mod localizable;
 pub mod markdown;
+pub mod modals;
 pub mod postgres;
+pub mod presence;
 
 /// A proxied Discord HTTP cliemt.
 pub static CLIENT: LazyLock<Client> = LazyLock::new(|| {
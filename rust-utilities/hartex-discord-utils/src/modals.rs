@@ -0,0 +1,42 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Utility Functions for Modals
+
+use hartex_discord_core::discord::model::application::interaction::modal::ModalInteractionData;
+
+/// Extension functions for `ModalInteractionData`.
+pub trait ModalInteractionDataExt {
+    /// Returns the value of a text input from a submitted modal.
+    fn text_input_value_of(&self, custom_id: &str) -> String;
+}
+
+impl ModalInteractionDataExt for ModalInteractionData {
+    fn text_input_value_of(&self, custom_id: &str) -> String {
+        self.components
+            .iter()
+            .flat_map(|row| row.components.iter())
+            .find(|component| component.custom_id.as_str() == custom_id)
+            .and_then(|component| component.value.clone())
+            .unwrap_or_default()
+    }
+}
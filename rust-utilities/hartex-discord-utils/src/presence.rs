@@ -0,0 +1,177 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Gateway Presence
+//!
+//! The presence of the bot is configured by the operator through the environment and may be
+//! changed at runtime by sending it to the leader.
+
+use std::env;
+
+use hartex_discord_core::discord::model::gateway::OpCode;
+use hartex_discord_core::discord::model::gateway::payload::outgoing::UpdatePresence;
+use hartex_discord_core::discord::model::gateway::payload::outgoing::update_presence::UpdatePresencePayload;
+use hartex_discord_core::discord::model::gateway::presence::Activity;
+use hartex_discord_core::discord::model::gateway::presence::ActivityType;
+use hartex_discord_core::discord::model::gateway::presence::Status;
+use miette::miette;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The placeholder in the activity name that is replaced with the shard number.
+pub const SHARD_PLACEHOLDER: &str = "{shard}";
+
+/// The presence of the bot.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Presence {
+    /// The activity to display, if any.
+    pub activity: Option<PresenceActivity>,
+    /// The online status.
+    pub status: Status,
+}
+
+/// The activity displayed in the presence of the bot.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PresenceActivity {
+    /// The type of the activity.
+    pub kind: ActivityType,
+    /// The text of the activity.
+    pub name: String,
+}
+
+impl Presence {
+    /// Read the presence from the environment.
+    ///
+    /// The status is read from `GATEWAY_PRESENCE_STATUS`, defaulting to `online`. An activity is
+    /// only displayed if `GATEWAY_PRESENCE_ACTIVITY_NAME` is set, with its type read from
+    /// `GATEWAY_PRESENCE_ACTIVITY_KIND`, defaulting to `playing`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the status or activity type is not recognised.
+    pub fn from_env() -> miette::Result<Self> {
+        let status = env::var("GATEWAY_PRESENCE_STATUS")
+            .ok()
+            .filter(|status| !status.is_empty())
+            .map_or(Ok(Status::Online), |status| {
+                parse_status(&status).ok_or_else(|| miette!("unknown presence status: {status}"))
+            })?;
+
+        let activity = match env::var("GATEWAY_PRESENCE_ACTIVITY_NAME") {
+            Ok(name) if !name.is_empty() => {
+                let kind = env::var("GATEWAY_PRESENCE_ACTIVITY_KIND")
+                    .ok()
+                    .filter(|kind| !kind.is_empty())
+                    .map_or(Ok(ActivityType::Playing), |kind| {
+                        parse_activity_kind(&kind)
+                            .ok_or_else(|| miette!("unknown presence activity kind: {kind}"))
+                    })?;
+
+                Some(PresenceActivity { kind, name })
+            }
+            _ => None,
+        };
+
+        Ok(Self { activity, status })
+    }
+
+    /// Build the presence payload sent by the given shard.
+    #[must_use]
+    pub fn payload(&self, shard_id: u32) -> UpdatePresencePayload {
+        let activities = self
+            .activity
+            .iter()
+            .map(|activity| {
+                let text = activity
+                    .name
+                    .replace(SHARD_PLACEHOLDER, &shard_id.to_string());
+
+                // custom statuses display the state rather than the name
+                let (name, state) = if activity.kind == ActivityType::Custom {
+                    (String::from("Custom Status"), Some(text))
+                } else {
+                    (text, None)
+                };
+
+                Activity {
+                    application_id: None,
+                    assets: None,
+                    buttons: vec![],
+                    created_at: None,
+                    details: None,
+                    emoji: None,
+                    flags: None,
+                    id: None,
+                    instance: None,
+                    kind: activity.kind,
+                    name,
+                    party: None,
+                    secrets: None,
+                    state,
+                    timestamps: None,
+                    url: None,
+                }
+            })
+            .collect();
+
+        UpdatePresencePayload {
+            activities,
+            afk: false,
+            since: None,
+            status: self.status,
+        }
+    }
+
+    /// Build the gateway command updating the presence of the given shard.
+    #[must_use]
+    pub fn command(&self, shard_id: u32) -> UpdatePresence {
+        UpdatePresence {
+            d: self.payload(shard_id),
+            op: OpCode::PresenceUpdate,
+        }
+    }
+}
+
+/// Parse an online status from its name.
+#[must_use]
+pub fn parse_status(status: &str) -> Option<Status> {
+    match status {
+        "dnd" => Some(Status::DoNotDisturb),
+        "idle" => Some(Status::Idle),
+        "invisible" => Some(Status::Invisible),
+        "online" => Some(Status::Online),
+        _ => None,
+    }
+}
+
+/// Parse an activity type from its name.
+#[must_use]
+pub fn parse_activity_kind(kind: &str) -> Option<ActivityType> {
+    match kind {
+        "competing" => Some(ActivityType::Competing),
+        "custom" => Some(ActivityType::Custom),
+        "listening" => Some(ActivityType::Listening),
+        "playing" => Some(ActivityType::Playing),
+        "watching" => Some(ActivityType::Watching),
+        _ => None,
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2025 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Dead Letters
+//!
+//! Records that cannot be processed are published to a dead-letter topic as-is, with the reason
+//! and their origin recorded in the record headers, such that they can be inspected and replayed
+//! once a fix is deployed.
+
+use std::str;
+use std::time::Duration;
+
+use rdkafka::error::KafkaResult;
+use rdkafka::message::Header;
+use rdkafka::message::Headers;
+use rdkafka::message::OwnedHeaders;
+use rdkafka::producer::FutureProducer;
+use rdkafka::producer::FutureRecord;
+use rdkafka::util::Timeout;
+
+/// Header recording the consumer that failed to process the record.
+pub const CONSUMER_HEADER: &str = "hartex-dead-letter-consumer";
+
+/// Header recording the error that occurred.
+pub const ERROR_HEADER: &str = "hartex-dead-letter-error";
+
+/// Header recording the event type of the gateway payload.
+pub const EVENT_TYPE_HEADER: &str = "hartex-dead-letter-event-type";
+
+/// Header recording the topic the record was originally published to.
+pub const ORIGIN_TOPIC_HEADER: &str = "hartex-dead-letter-origin-topic";
+
+/// Header recording the shard the gateway payload was received on.
+pub const SHARD_HEADER: &str = "hartex-dead-letter-shard";
+
+/// Information about a record that could not be processed.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct DeadLetter {
+    /// The consumer that failed to process the record.
+    pub consumer: String,
+    /// The error that occurred.
+    pub error: String,
+    /// The event type of the gateway payload, if it could be determined.
+    pub event_type: Option<String>,
+    /// The topic the record was originally published to.
+    pub origin_topic: String,
+    /// The shard the gateway payload was received on, if it could be determined.
+    pub shard: Option<u32>,
+}
+
+impl DeadLetter {
+    /// Reads a dead letter from the headers of a record on the dead-letter topic.
+    ///
+    /// Returns `None` if any of the required headers are missing.
+    pub fn from_headers<H>(headers: &H) -> Option<Self>
+    where
+        H: Headers,
+    {
+        let mut consumer = None;
+        let mut error = None;
+        let mut event_type = None;
+        let mut origin_topic = None;
+        let mut shard = None;
+
+        for header in headers.iter() {
+            let Some(value) = header.value.and_then(|value| str::from_utf8(value).ok()) else {
+                continue;
+            };
+
+            match header.key {
+                CONSUMER_HEADER => consumer = Some(value.to_string()),
+                ERROR_HEADER => error = Some(value.to_string()),
+                EVENT_TYPE_HEADER => event_type = Some(value.to_string()),
+                ORIGIN_TOPIC_HEADER => origin_topic = Some(value.to_string()),
+                SHARD_HEADER => shard = value.parse().ok(),
+                _ => (),
+            }
+        }
+
+        Some(Self {
+            consumer: consumer?,
+            error: error?,
+            event_type,
+            origin_topic: origin_topic?,
+            shard,
+        })
+    }
+
+    /// Converts the dead letter into record headers.
+    #[must_use]
+    pub fn to_headers(&self) -> OwnedHeaders {
+        let shard = self.shard.map(|shard| shard.to_string());
+
+        [
+            (CONSUMER_HEADER, Some(self.consumer.as_str())),
+            (ERROR_HEADER, Some(self.error.as_str())),
+            (EVENT_TYPE_HEADER, self.event_type.as_deref()),
+            (ORIGIN_TOPIC_HEADER, Some(self.origin_topic.as_str())),
+            (SHARD_HEADER, shard.as_deref()),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|value| (key, value)))
+        .fold(OwnedHeaders::new(), |headers, (key, value)| {
+            headers.insert(Header {
+                key,
+                value: Some(value),
+            })
+        })
+    }
+
+    /// Publishes a record that could not be processed to the dead-letter topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record could not be delivered.
+    pub async fn publish(
+        &self,
+        producer: &FutureProducer,
+        topic: &str,
+        key: Option<&[u8]>,
+        payload: &[u8],
+    ) -> KafkaResult<()> {
+        let mut record = FutureRecord::<[u8], [u8]>::to(topic)
+            .payload(payload)
+            .headers(self.to_headers());
+        if let Some(key) = key {
+            record = record.key(key);
+        }
+
+        producer
+            .send(record, Timeout::After(Duration::from_secs(0)))
+            .await
+            .map(|_| ())
+            .map_err(|(error, _)| error)
+    }
+}
@@ -27,5 +27,6 @@
 #![deny(warnings)]
 #![feature(iter_intersperse)]
 
+pub mod deadletter;
 pub mod traits;
 pub mod types;
@@ -28,6 +28,9 @@ use crate::types::CompressionType;
 
 /// Extension functions for Kafka client configuration.
 pub trait ClientConfigUtils {
+    /// Configure where to start consuming when there is no committed offset.
+    fn auto_offset_reset(&mut self, reset: &str) -> &mut Self;
+
     /// Configure bootstrap servers.
     fn bootstrap_servers(&mut self, servers: impl Iterator<Item = String>) -> &mut Self;
 
@@ -37,11 +40,18 @@ pub trait ClientConfigUtils {
     /// Configure the duration for delivery timeout.
     fn delivery_timeout_ms(&mut self, timeout: u32) -> &mut Self;
 
+    /// Configure whether offsets are committed automatically.
+    fn enable_auto_commit(&mut self, enable: bool) -> &mut Self;
+
     /// Configure group id.
     fn group_id(&mut self, group_id: &str) -> &mut Self;
 }
 
 impl ClientConfigUtils for ClientConfig {
+    fn auto_offset_reset(&mut self, reset: &str) -> &mut Self {
+        self.set("auto.offset.reset", reset)
+    }
+
     fn bootstrap_servers(&mut self, servers: impl Iterator<Item = String>) -> &mut Self {
         self.set(
             "bootstrap.servers",
@@ -57,6 +67,10 @@ impl ClientConfigUtils for ClientConfig {
         self.set("delivery.timeout.ms", timeout.to_string())
     }
 
+    fn enable_auto_commit(&mut self, enable: bool) -> &mut Self {
+        self.set("enable.auto.commit", enable.to_string())
+    }
+
     fn group_id(&mut self, group_id: &str) -> &mut Self {
         self.set("group.id", group_id)
     }